@@ -101,6 +101,36 @@ impl SatKernel {
     }
 }
 
+// STANDARD NOTE SCRIPTS
+// ================================================================================================
+
+pub struct StandardNotes;
+
+impl StandardNotes {
+    /// Returns masm source code of the pay-to-ID note script.
+    ///
+    /// The note can be consumed only by the account with the ID specified by the note inputs.
+    pub fn p2id() -> &'static str {
+        include_str!("../note_scripts/P2ID.masm")
+    }
+
+    /// Returns masm source code of the pay-to-ID-with-reclaim note script.
+    ///
+    /// The note can be consumed by the account with the ID specified by the note inputs, or by the
+    /// sender of the note once the reclaim block height specified by the note inputs is reached.
+    pub fn p2idr() -> &'static str {
+        include_str!("../note_scripts/P2IDR.masm")
+    }
+
+    /// Returns masm source code of the swap note script.
+    ///
+    /// The note can be consumed by any account which sends the requested asset specified by the
+    /// note inputs back to the sender of the note in a payback note.
+    pub fn swap() -> &'static str {
+        include_str!("../note_scripts/SWAP.masm")
+    }
+}
+
 // TEST
 // ================================================================================================
 
@@ -154,6 +154,15 @@ pub const ACCT_NEW_CODE_ROOT_OFFSET: MemoryOffset = 4;
 pub const ACCT_NEW_CODE_ROOT_PTR: MemoryAddress =
     ACCT_DATA_SECTION_OFFSET + ACCT_NEW_CODE_ROOT_OFFSET;
 
+/// The offset at which the account storage slot types are stored relative to the start of the
+/// account data segment.
+pub const ACCT_STORAGE_SLOT_TYPE_DATA_OFFSET: MemoryOffset = 5;
+
+/// The memory address at which the account storage slot types begin. The types of the 256 storage
+/// slots are stored in 64 consecutive words, with the types of four slots stored in each word.
+pub const ACCT_STORAGE_SLOT_TYPE_DATA_PTR: MemoryAddress =
+    ACCT_DATA_SECTION_OFFSET + ACCT_STORAGE_SLOT_TYPE_DATA_OFFSET;
+
 // NOTES DATA
 // ------------------------------------------------------------------------------------------------
 
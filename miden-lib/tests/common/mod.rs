@@ -47,8 +47,10 @@ pub fn run_tx<A>(
 where
     A: AdviceProvider,
 {
-    // mock account method for testing from root context
-    adv.insert_into_map(Word::default(), vec![Felt::new(255)]).unwrap();
+    // mock account method for testing from root context: the procedure is at index 255 and has
+    // empty metadata
+    adv.insert_into_map(Word::default(), vec![Felt::new(255), ZERO, ZERO, ZERO, ZERO])
+        .unwrap();
 
     let mut process =
         Process::new(program.kernel().clone(), stack_inputs, adv, ExecutionOptions::default());
@@ -68,8 +70,10 @@ pub fn run_within_tx_kernel<A>(
 where
     A: AdviceProvider,
 {
-    // mock account method for testing from root context
-    adv.insert_into_map(Word::default(), vec![Felt::new(255)]).unwrap();
+    // mock account method for testing from root context: the procedure is at index 255 and has
+    // empty metadata
+    adv.insert_into_map(Word::default(), vec![Felt::new(255), ZERO, ZERO, ZERO, ZERO])
+        .unwrap();
 
     let assembler = assembler();
 
@@ -1,5 +1,6 @@
 pub mod common;
 use common::{
+    assembler,
     data::{
         mock_inputs, AccountStatus, AssetPreservationStatus, CHILD_ROOT_PARENT_LEAF_INDEX,
         CHILD_SMT_DEPTH, CHILD_STORAGE_INDEX_0, CHILD_STORAGE_VALUE_0, STORAGE_ITEM_0,
@@ -9,19 +10,41 @@ use common::{
     prepare_transaction,
     procedures::created_notes_data_procedure,
     procedures::prepare_word,
-    run_tx, run_within_tx_kernel, AccountId, AccountType, Felt, MemAdviceProvider, Word, ONE, ZERO,
+    run_tx, run_within_tx_kernel, Account, AccountCode, AccountId, AccountStorage, AccountType,
+    Felt, MemAdviceProvider, MerkleStore, NodeIndex, Word, ONE, ZERO,
 };
-use miden_objects::mock::mock_executed_tx;
+use miden_objects::{mock::mock_executed_tx, ProcedureMetadata, StorageMap, StorageSlotType};
+use std::collections::BTreeMap;
 use vm_core::StackInputs;
 
 // MOCK DATA
 // ================================================================================================
 
-const ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN: u64 = 0b0110011011u64 << 54;
-const ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN: u64 = 0b0001101110 << 54;
-const ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN: u64 = 0b1010011100 << 54;
-const ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN: u64 = 0b1101100110 << 54;
+const ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN: u64 = 0b0100110111u64 << 54;
+const ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN: u64 = 0b0011101110 << 54;
+const ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN: u64 = 0b1000111100 << 54;
+const ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN: u64 = 0b1111100110 << 54;
 const ACCOUNT_ID_INSUFFICIENT_ONES: u64 = 0b1100000110 << 54;
+const ACCOUNT_ID_INVALID_STORAGE_MODE: u64 = 0b0010111011 << 54;
+
+const STORAGE_MAP_SLOT: u8 = 10;
+const STORAGE_MAP_KEY_0: Word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+const STORAGE_MAP_VALUE_0: Word = [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)];
+const STORAGE_MAP_KEY_1: Word = [Felt::new(9), Felt::new(10), Felt::new(11), Felt::new(12)];
+const STORAGE_MAP_VALUE_1: Word = [Felt::new(13), Felt::new(14), Felt::new(15), Felt::new(16)];
+
+/// Replaces the storage of the provided account with a storage holding a single map.
+fn with_storage_map(account: Account, map: StorageMap) -> Account {
+    let storage =
+        AccountStorage::new(vec![], vec![(STORAGE_MAP_SLOT, map)], MerkleStore::new()).unwrap();
+    Account::new(
+        account.id(),
+        account.vault().clone(),
+        storage,
+        account.code().clone(),
+        account.nonce(),
+    )
+}
 
 // TESTS
 // ================================================================================================
@@ -187,6 +210,31 @@ fn test_validate_id_fails_on_insuficcient_ones() {
     assert!(result.is_err());
 }
 
+#[test]
+fn test_validate_id_fails_on_invalid_storage_mode() {
+    let code = format!(
+        "
+        use.miden::sat::internal::account
+
+        begin
+            push.{ACCOUNT_ID_INVALID_STORAGE_MODE}
+            exec.account::validate_id
+        end
+        "
+    );
+
+    let result = run_within_tx_kernel(
+        "",
+        &code,
+        StackInputs::default(),
+        MemAdviceProvider::default(),
+        None,
+        None,
+    );
+
+    assert!(result.is_err());
+}
+
 #[test]
 fn test_get_item() {
     for storage_item in [STORAGE_ITEM_0, STORAGE_ITEM_1] {
@@ -212,8 +260,8 @@ fn test_get_item() {
             push.{item_value} assert_eqw
         end
         ",
-            item_index = storage_item.0,
-            item_value = prepare_word(&storage_item.1)
+            item_index = storage_item.index(),
+            item_value = prepare_word(&storage_item.value())
         );
 
         let transaction =
@@ -338,6 +386,299 @@ fn test_set_item() {
     .unwrap();
 }
 
+#[test]
+fn test_get_map_item() {
+    let (account, block_header, chain, notes) = mock_inputs(AccountStatus::Existing);
+    let map = StorageMap::with_entries([(STORAGE_MAP_KEY_0, STORAGE_MAP_VALUE_0)]).unwrap();
+    let account = with_storage_map(account, map);
+
+    let code = format!(
+        "
+    use.miden::sat::internal::account
+    use.miden::sat::internal::prologue
+
+    begin
+        # prepare the transaction
+        exec.prologue::prepare_transaction
+
+        # get an existing map item and assert the value is correct
+        push.{key_0} push.{STORAGE_MAP_SLOT}
+        exec.account::get_map_item
+        push.{value_0} assert_eqw
+
+        # get a missing map item and assert the value is empty
+        push.{key_1} push.{STORAGE_MAP_SLOT}
+        exec.account::get_map_item
+        padw assert_eqw
+    end
+    ",
+        key_0 = prepare_word(&STORAGE_MAP_KEY_0),
+        value_0 = prepare_word(&STORAGE_MAP_VALUE_0),
+        key_1 = prepare_word(&STORAGE_MAP_KEY_1),
+    );
+
+    let transaction =
+        prepare_transaction(account, None, block_header, chain, notes, &code, "", None, None);
+
+    let _process = run_tx(
+        transaction.tx_program().clone(),
+        StackInputs::from(transaction.stack_inputs()),
+        MemAdviceProvider::from(transaction.advice_provider_inputs()),
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_set_map_item() {
+    let (account, block_header, chain, notes) = mock_inputs(AccountStatus::Existing);
+    let map = StorageMap::with_entries([(STORAGE_MAP_KEY_0, STORAGE_MAP_VALUE_0)]).unwrap();
+    let account = with_storage_map(account, map);
+
+    // compute the expected storage after the update
+    let mut storage = account.storage().clone();
+    let init_map_root = storage.get_item(STORAGE_MAP_SLOT);
+    storage
+        .set_map_item(STORAGE_MAP_SLOT, STORAGE_MAP_KEY_1, STORAGE_MAP_VALUE_1)
+        .unwrap();
+
+    let code = format!(
+        "
+    use.miden::sat::internal::account
+    use.miden::sat::internal::layout
+    use.miden::sat::internal::prologue
+
+    begin
+        # prepare the transaction
+        exec.prologue::prepare_transaction
+
+        # set a new map item
+        push.{value_1} push.{key_1} push.{STORAGE_MAP_SLOT}
+        exec.account::set_map_item
+
+        # assert the old map root and the empty old value are returned
+        push.{init_map_root} assert_eqw
+        padw assert_eqw
+
+        # assert the storage root was updated
+        exec.layout::get_acct_storage_root
+        push.{new_storage_root} assert_eqw
+
+        # assert the new map item can be read back
+        push.{key_1} push.{STORAGE_MAP_SLOT}
+        exec.account::get_map_item
+        push.{value_1} assert_eqw
+    end
+    ",
+        key_1 = prepare_word(&STORAGE_MAP_KEY_1),
+        value_1 = prepare_word(&STORAGE_MAP_VALUE_1),
+        init_map_root = prepare_word(&init_map_root),
+        new_storage_root = prepare_word(&storage.root()),
+    );
+
+    let transaction =
+        prepare_transaction(account, None, block_header, chain, notes, &code, "", None, None);
+
+    let _process = run_tx(
+        transaction.tx_program().clone(),
+        StackInputs::from(transaction.stack_inputs()),
+        MemAdviceProvider::from(transaction.advice_provider_inputs()),
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_set_array_item() {
+    let (account, block_header, chain, notes) = mock_inputs(AccountStatus::Existing);
+    const NEW_CHILD_VALUE: Word = [Felt::new(91), Felt::new(92), Felt::new(93), Felt::new(94)];
+
+    // compute the expected storage after the update
+    let mut storage = account.storage().clone();
+    let init_array_root = storage.get_item(CHILD_ROOT_PARENT_LEAF_INDEX);
+    let node_index = NodeIndex::new(CHILD_SMT_DEPTH, CHILD_STORAGE_INDEX_0).unwrap();
+    storage
+        .set_store_node(CHILD_ROOT_PARENT_LEAF_INDEX, node_index, NEW_CHILD_VALUE.into())
+        .unwrap();
+
+    let code = format!(
+        "
+    use.miden::sat::internal::account
+    use.miden::sat::internal::layout
+    use.miden::sat::internal::prologue
+
+    begin
+        # prepare the transaction
+        exec.prologue::prepare_transaction
+
+        # update an existing array item
+        push.{new_value} push.{CHILD_STORAGE_INDEX_0} push.{CHILD_ROOT_PARENT_LEAF_INDEX}
+        exec.account::set_array_item
+
+        # assert the old array root and the old value are returned
+        push.{init_array_root} assert_eqw
+        push.{old_value} assert_eqw
+
+        # assert the storage root was updated
+        exec.layout::get_acct_storage_root
+        push.{new_storage_root} assert_eqw
+    end
+    ",
+        new_value = prepare_word(&NEW_CHILD_VALUE),
+        init_array_root = prepare_word(&init_array_root),
+        old_value = prepare_word(&CHILD_STORAGE_VALUE_0),
+        new_storage_root = prepare_word(&storage.root()),
+    );
+
+    let transaction =
+        prepare_transaction(account, None, block_header, chain, notes, &code, "", None, None);
+
+    let _process = run_tx(
+        transaction.tx_program().clone(),
+        StackInputs::from(transaction.stack_inputs()),
+        MemAdviceProvider::from(transaction.advice_provider_inputs()),
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_set_item_fails_on_array_slot() {
+    let (account, block_header, chain, notes) = mock_inputs(AccountStatus::Existing);
+
+    let code = format!(
+        "
+    use.miden::sat::internal::account
+    use.miden::sat::internal::prologue
+
+    begin
+        # prepare the transaction
+        exec.prologue::prepare_transaction
+
+        # try to replace the array root directly
+        push.{value} push.{CHILD_ROOT_PARENT_LEAF_INDEX}
+        exec.account::set_item
+    end
+    ",
+        value = prepare_word(&CHILD_STORAGE_VALUE_0),
+    );
+
+    let transaction =
+        prepare_transaction(account, None, block_header, chain, notes, &code, "", None, None);
+
+    let process = run_tx(
+        transaction.tx_program().clone(),
+        StackInputs::from(transaction.stack_inputs()),
+        MemAdviceProvider::from(transaction.advice_provider_inputs()),
+    );
+
+    assert!(process.is_err());
+}
+
+#[test]
+fn test_set_array_item_fails_on_value_slot() {
+    let (account, block_header, chain, notes) = mock_inputs(AccountStatus::Existing);
+
+    let code = format!(
+        "
+    use.miden::sat::internal::account
+    use.miden::sat::internal::prologue
+
+    begin
+        # prepare the transaction
+        exec.prologue::prepare_transaction
+
+        # try to set an array item in a value slot
+        push.{value} push.0 push.{value_slot}
+        exec.account::set_array_item
+    end
+    ",
+        value = prepare_word(&CHILD_STORAGE_VALUE_0),
+        value_slot = STORAGE_ITEM_0.index(),
+    );
+
+    let transaction =
+        prepare_transaction(account, None, block_header, chain, notes, &code, "", None, None);
+
+    let process = run_tx(
+        transaction.tx_program().clone(),
+        StackInputs::from(transaction.stack_inputs()),
+        MemAdviceProvider::from(transaction.advice_provider_inputs()),
+    );
+
+    assert!(process.is_err());
+}
+
+#[test]
+fn test_get_storage_slot_type() {
+    let (account, block_header, chain, notes) = mock_inputs(AccountStatus::Existing);
+    let account = with_storage_map(account, StorageMap::new());
+
+    let code = format!(
+        "
+    use.miden::sat::internal::account
+    use.miden::sat::internal::prologue
+
+    begin
+        # prepare the transaction
+        exec.prologue::prepare_transaction
+
+        # assert the map slot has the map type
+        push.{STORAGE_MAP_SLOT} exec.account::get_storage_slot_type
+        push.{map_type} assert_eq
+
+        # assert the other slots have the value type
+        push.{value_slot} exec.account::get_storage_slot_type
+        push.{value_type} assert_eq
+    end
+    ",
+        map_type = Felt::from(StorageSlotType::Map),
+        value_slot = STORAGE_MAP_SLOT + 1,
+        value_type = Felt::from(StorageSlotType::Value),
+    );
+
+    let transaction =
+        prepare_transaction(account, None, block_header, chain, notes, &code, "", None, None);
+
+    let _process = run_tx(
+        transaction.tx_program().clone(),
+        StackInputs::from(transaction.stack_inputs()),
+        MemAdviceProvider::from(transaction.advice_provider_inputs()),
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_set_item_fails_on_map_slot() {
+    let (account, block_header, chain, notes) = mock_inputs(AccountStatus::Existing);
+    let account = with_storage_map(account, StorageMap::new());
+
+    let code = format!(
+        "
+    use.miden::sat::internal::account
+    use.miden::sat::internal::prologue
+
+    begin
+        # prepare the transaction
+        exec.prologue::prepare_transaction
+
+        # try to set the map slot directly
+        push.{value} push.{STORAGE_MAP_SLOT}
+        exec.account::set_item
+    end
+    ",
+        value = prepare_word(&STORAGE_MAP_VALUE_0),
+    );
+
+    let transaction =
+        prepare_transaction(account, None, block_header, chain, notes, &code, "", None, None);
+
+    let process = run_tx(
+        transaction.tx_program().clone(),
+        StackInputs::from(transaction.stack_inputs()),
+        MemAdviceProvider::from(transaction.advice_provider_inputs()),
+    );
+
+    assert!(process.is_err());
+}
+
 #[test]
 fn test_is_faucet_procedure() {
     let test_cases = vec![
@@ -429,3 +770,83 @@ fn test_authenticate_procedure() {
         }
     }
 }
+
+#[test]
+fn test_authenticate_tx_script_only_procedure() {
+    let (account, _, _, _) = mock_inputs(AccountStatus::Existing);
+
+    // restrict the `set_item` procedure of the mock account to the transaction script
+    let metadata = BTreeMap::from([("set_item".to_string(), ProcedureMetadata::new(true))]);
+    let code = AccountCode::with_metadata(
+        account.id(),
+        account.code().module().clone(),
+        &metadata,
+        &assembler(),
+    )
+    .unwrap();
+    let proc_idx = code.procedure_metadata().iter().position(|m| m.is_tx_script_only()).unwrap();
+    let proc_root: Word = code.get_procedure_by_index(proc_idx).into();
+    let account = Account::new(
+        account.id(),
+        account.vault().clone(),
+        account.storage().clone(),
+        code,
+        account.nonce(),
+    );
+
+    // the procedure is authenticated outside of note execution, but not while a note is executed
+    for (executing_note, valid) in [(false, true), (true, false)] {
+        let (_, block_header, chain, notes) = mock_inputs(AccountStatus::Existing);
+
+        let set_note_ptr = if executing_note {
+            "push.0 exec.layout::get_consumed_note_ptr exec.layout::set_current_consumed_note_ptr"
+        } else {
+            ""
+        };
+        let code = format!(
+            "\
+            use.miden::sat::internal::account
+            use.miden::sat::internal::layout
+            use.miden::sat::internal::prologue
+
+            begin
+                # prepare the transaction
+                exec.prologue::prepare_transaction
+
+                # simulate the execution of a note
+                {set_note_ptr}
+
+                # push test procedure root onto stack
+                push.{root}
+
+                # authenticate procedure
+                exec.account::authenticate_procedure
+            end
+        ",
+            root = prepare_word(&proc_root)
+        );
+
+        let transaction = prepare_transaction(
+            account.clone(),
+            None,
+            block_header,
+            chain,
+            notes,
+            &code,
+            "",
+            None,
+            None,
+        );
+
+        let process = run_tx(
+            transaction.tx_program().clone(),
+            StackInputs::from(transaction.stack_inputs()),
+            MemAdviceProvider::from(transaction.advice_provider_inputs()),
+        );
+
+        match valid {
+            true => assert!(process.is_ok()),
+            false => assert!(process.is_err()),
+        }
+    }
+}
@@ -11,7 +11,7 @@ use common::{
 };
 use crypto::StarkField;
 use miden_lib::memory;
-use miden_objects::assets::FungibleAsset;
+use miden_objects::assets::{FungibleAsset, NonFungibleAssetDetailsRegistry};
 
 #[test]
 fn test_get_balance() {
@@ -453,3 +453,135 @@ fn test_remove_non_fungible_asset_success() {
         *account_vault.commitment()
     );
 }
+
+#[test]
+fn test_validate_non_fungible_asset_data() {
+    let (account, block_header, chain, notes) = mock_inputs(AccountStatus::Existing);
+
+    let faucet_id: AccountId = ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN.try_into().unwrap();
+    let details = NonFungibleAssetDetails::new(faucet_id, vec![7; 40]).unwrap();
+    let mut registry = NonFungibleAssetDetailsRegistry::new();
+    let non_fungible_asset = registry.insert(details.clone()).unwrap();
+
+    let data_ptr = 2000;
+    let code = format!(
+        "
+        use.miden::sat::internal::asset
+
+        begin
+            push.{data_ptr}
+            push.{ASSET}
+            exec.asset::validate_non_fungible_asset_data
+        end
+    ",
+        ASSET = prepare_word(&non_fungible_asset.into())
+    );
+
+    let transaction =
+        prepare_transaction(account, None, block_header, chain, notes, &code, "", None, None);
+
+    // the asset data is authenticated against the asset and written to memory
+    let mut advice_inputs = transaction.advice_provider_inputs();
+    advice_inputs.extend_map(registry.advice_map_entries());
+    let process = run_tx(
+        transaction.tx_program().clone(),
+        transaction.stack_inputs(),
+        MemAdviceProvider::from(advice_inputs),
+    )
+    .unwrap();
+
+    let data = details.data_elements();
+    assert_eq!(process.stack.get(0), Felt::new(data.len() as u64 / 4));
+    assert_eq!(process.stack.get_word(1), Word::from(non_fungible_asset));
+    for (i, word) in data.chunks(4).enumerate() {
+        assert_eq!(process.get_memory_value(0, data_ptr + i as u32).unwrap(), word);
+    }
+
+    // data which is not the preimage of the asset is rejected
+    let other_details = NonFungibleAssetDetails::new(faucet_id, vec![8; 40]).unwrap();
+    let other_data = other_details.data_elements();
+    let mut values = vec![Felt::new(other_data.len() as u64 / 4)];
+    values.extend(other_data);
+
+    let mut advice_inputs = transaction.advice_provider_inputs();
+    advice_inputs.extend_map([(non_fungible_asset.into(), values)]);
+    let process = run_tx(
+        transaction.tx_program().clone(),
+        transaction.stack_inputs(),
+        MemAdviceProvider::from(advice_inputs),
+    );
+
+    assert!(process.is_err());
+}
+
+#[test]
+fn test_sat_validate_non_fungible_asset_data() {
+    let (account, block_header, chain, notes) = mock_inputs(AccountStatus::Existing);
+
+    let faucet_id: AccountId = ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN.try_into().unwrap();
+    let details = NonFungibleAssetDetails::new(faucet_id, vec![7; 40]).unwrap();
+    let mut registry = NonFungibleAssetDetailsRegistry::new();
+    let non_fungible_asset = registry.insert(details.clone()).unwrap();
+
+    let data_ptr = 2000;
+    let code = format!(
+        "
+        use.miden::sat::asset
+
+        begin
+            push.{data_ptr}
+            push.{ASSET}
+            exec.asset::validate_non_fungible_asset_data
+        end
+    ",
+        ASSET = prepare_word(&non_fungible_asset.into())
+    );
+
+    let transaction =
+        prepare_transaction(account, None, block_header, chain, notes, &code, "", None, None);
+
+    // the asset data is authenticated against the asset and written to memory
+    let mut advice_inputs = transaction.advice_provider_inputs();
+    advice_inputs.extend_map(registry.advice_map_entries());
+    let process = run_tx(
+        transaction.tx_program().clone(),
+        transaction.stack_inputs(),
+        MemAdviceProvider::from(advice_inputs),
+    )
+    .unwrap();
+
+    let data = details.data_elements();
+    assert_eq!(process.stack.get(0), Felt::new(data.len() as u64 / 4));
+    assert_eq!(process.stack.get_word(1), Word::from(non_fungible_asset));
+    for (i, word) in data.chunks(4).enumerate() {
+        assert_eq!(process.get_memory_value(0, data_ptr + i as u32).unwrap(), word);
+    }
+
+    // fungible assets are rejected by the transaction kernel
+    let (account, block_header, chain, notes) = mock_inputs(AccountStatus::Existing);
+    let faucet_id: AccountId = ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN.try_into().unwrap();
+    let fungible_asset: Asset =
+        FungibleAsset::new(faucet_id, FUNGIBLE_ASSET_AMOUNT).unwrap().into();
+    let code = format!(
+        "
+        use.miden::sat::asset
+
+        begin
+            push.{data_ptr}
+            push.{ASSET}
+            exec.asset::validate_non_fungible_asset_data
+        end
+    ",
+        ASSET = prepare_word(&fungible_asset.into())
+    );
+
+    let transaction =
+        prepare_transaction(account, None, block_header, chain, notes, &code, "", None, None);
+    let process = run_tx(
+        transaction.tx_program().clone(),
+        transaction.stack_inputs(),
+        MemAdviceProvider::from(transaction.advice_provider_inputs()),
+    );
+
+    assert!(process.is_err());
+}
@@ -0,0 +1,378 @@
+pub mod common;
+use common::{
+    assembler,
+    data::{
+        mock_fungible_faucet, mock_inputs, mock_inputs_with_note_assets, mock_non_fungible_faucet,
+        AccountStatus, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1,
+        ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, NON_FUNGIBLE_ASSET_DATA,
+    },
+    prepare_transaction,
+    procedures::prepare_word,
+    run_tx, Account, AccountId, AccountStorage, Asset, BlockHeader, ChainMmr, ExecutionError,
+    FungibleAsset, MemAdviceProvider, NonFungibleAsset, NonFungibleAssetDetails, Note, Process,
+    StackInputs, Word,
+};
+
+// MOCK DATA
+// ================================================================================================
+
+const INITIAL_ISSUANCE: u64 = 100;
+const MINT_AMOUNT: u64 = 50;
+const BURN_AMOUNT: u64 = 30;
+
+// FUNGIBLE ASSETS
+// ================================================================================================
+
+#[test]
+fn test_mint_fungible_asset_succeeds() {
+    let (_, block_header, chain, notes) = mock_inputs(AccountStatus::Existing);
+    let account = mock_fungible_faucet(INITIAL_ISSUANCE, &mut assembler());
+    let asset: Asset = FungibleAsset::new(account.id(), MINT_AMOUNT).unwrap().into();
+
+    let code = format!(
+        "
+    use.miden::sat::faucet
+    use.miden::sat::internal::account
+    use.miden::sat::internal::prologue
+
+    begin
+        # prepare the transaction
+        exec.prologue::prepare_transaction
+
+        # mint the asset
+        push.{asset} exec.faucet::mint
+
+        # assert the minted asset is returned
+        push.{asset} assert_eqw
+
+        # assert the total issuance was updated
+        push.{issuance_slot} exec.account::get_item
+        push.{total_issuance}.0.0.0 assert_eqw
+    end
+    ",
+        asset = prepare_word(&asset.into()),
+        issuance_slot = AccountStorage::FAUCET_ISSUANCE_SLOT,
+        total_issuance = INITIAL_ISSUANCE + MINT_AMOUNT,
+    );
+
+    let process = run_faucet_tx(account, block_header, chain, notes, &code);
+    assert!(process.is_ok());
+}
+
+#[test]
+fn test_mint_fungible_asset_fails_for_other_faucet() {
+    let (_, block_header, chain, notes) = mock_inputs(AccountStatus::Existing);
+    let account = mock_fungible_faucet(INITIAL_ISSUANCE, &mut assembler());
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1).unwrap();
+    let asset: Asset = FungibleAsset::new(faucet_id, MINT_AMOUNT).unwrap().into();
+
+    let code = format!(
+        "
+    use.miden::sat::faucet
+    use.miden::sat::internal::prologue
+
+    begin
+        # prepare the transaction
+        exec.prologue::prepare_transaction
+
+        # mint an asset issued by another faucet
+        push.{asset} exec.faucet::mint
+    end
+    ",
+        asset = prepare_word(&asset.into()),
+    );
+
+    let process = run_faucet_tx(account, block_header, chain, notes, &code);
+    assert!(process.is_err());
+}
+
+#[test]
+fn test_mint_fungible_asset_fails_when_max_supply_exceeded() {
+    let (_, block_header, chain, notes) = mock_inputs(AccountStatus::Existing);
+    let initial_issuance = FungibleAsset::MAX_AMOUNT - MINT_AMOUNT + 1;
+    let account = mock_fungible_faucet(initial_issuance, &mut assembler());
+    let asset: Asset = FungibleAsset::new(account.id(), MINT_AMOUNT).unwrap().into();
+
+    let code = format!(
+        "
+    use.miden::sat::faucet
+    use.miden::sat::internal::prologue
+
+    begin
+        # prepare the transaction
+        exec.prologue::prepare_transaction
+
+        # mint an asset which takes the total issuance above the maximum amount
+        push.{asset} exec.faucet::mint
+    end
+    ",
+        asset = prepare_word(&asset.into()),
+    );
+
+    let process = run_faucet_tx(account, block_header, chain, notes, &code);
+    assert!(process.is_err());
+}
+
+#[test]
+fn test_burn_fungible_asset_succeeds() {
+    let account = mock_fungible_faucet(INITIAL_ISSUANCE, &mut assembler());
+    let asset: Asset = FungibleAsset::new(account.id(), BURN_AMOUNT).unwrap().into();
+    let (account, block_header, chain, notes) = mock_inputs_with_note_assets(account, &[asset]);
+
+    let code = format!(
+        "
+    use.miden::sat::faucet
+    use.miden::sat::internal::account
+    use.miden::sat::internal::prologue
+
+    begin
+        # prepare the transaction
+        exec.prologue::prepare_transaction
+
+        # burn the asset provided by the consumed note
+        push.{asset} exec.faucet::burn
+
+        # assert the burned asset is returned
+        push.{asset} assert_eqw
+
+        # assert the total issuance was updated
+        push.{issuance_slot} exec.account::get_item
+        push.{total_issuance}.0.0.0 assert_eqw
+    end
+    ",
+        asset = prepare_word(&asset.into()),
+        issuance_slot = AccountStorage::FAUCET_ISSUANCE_SLOT,
+        total_issuance = INITIAL_ISSUANCE - BURN_AMOUNT,
+    );
+
+    let process = run_faucet_tx(account, block_header, chain, notes, &code);
+    assert!(process.is_ok());
+}
+
+#[test]
+fn test_burn_fungible_asset_fails_when_not_provided_by_notes() {
+    let (_, block_header, chain, notes) = mock_inputs(AccountStatus::Existing);
+    let account = mock_fungible_faucet(INITIAL_ISSUANCE, &mut assembler());
+    let asset: Asset = FungibleAsset::new(account.id(), BURN_AMOUNT).unwrap().into();
+
+    let code = format!(
+        "
+    use.miden::sat::faucet
+    use.miden::sat::internal::prologue
+
+    begin
+        # prepare the transaction
+        exec.prologue::prepare_transaction
+
+        # burn an asset which is not in the input vault
+        push.{asset} exec.faucet::burn
+    end
+    ",
+        asset = prepare_word(&asset.into()),
+    );
+
+    let process = run_faucet_tx(account, block_header, chain, notes, &code);
+    assert!(process.is_err());
+}
+
+#[test]
+fn test_get_total_issuance_succeeds() {
+    let (_, block_header, chain, notes) = mock_inputs(AccountStatus::Existing);
+    let account = mock_fungible_faucet(INITIAL_ISSUANCE, &mut assembler());
+
+    let code = format!(
+        "
+    use.miden::sat::faucet
+    use.miden::sat::internal::prologue
+
+    begin
+        # prepare the transaction
+        exec.prologue::prepare_transaction
+
+        # assert the total issuance is returned
+        exec.faucet::get_total_issuance
+        push.{total_issuance} assert_eq
+    end
+    ",
+        total_issuance = INITIAL_ISSUANCE,
+    );
+
+    let process = run_faucet_tx(account, block_header, chain, notes, &code);
+    assert!(process.is_ok());
+}
+
+#[test]
+fn test_get_total_issuance_fails_for_non_fungible_faucet() {
+    let (_, block_header, chain, notes) = mock_inputs(AccountStatus::Existing);
+    let account = mock_non_fungible_faucet(&[], &mut assembler());
+
+    let code = "
+    use.miden::sat::faucet
+    use.miden::sat::internal::prologue
+
+    begin
+        # prepare the transaction
+        exec.prologue::prepare_transaction
+
+        # get the total issuance of a non-fungible faucet
+        exec.faucet::get_total_issuance
+    end
+    ";
+
+    let process = run_faucet_tx(account, block_header, chain, notes, code);
+    assert!(process.is_err());
+}
+
+// NON-FUNGIBLE ASSETS
+// ================================================================================================
+
+#[test]
+fn test_mint_non_fungible_asset_succeeds() {
+    let (_, block_header, chain, notes) = mock_inputs(AccountStatus::Existing);
+    let asset = non_fungible_asset();
+    let account = mock_non_fungible_faucet(&[], &mut assembler());
+
+    let code = format!(
+        "
+    use.miden::sat::faucet
+    use.miden::sat::internal::account
+    use.miden::sat::internal::prologue
+
+    begin
+        # prepare the transaction
+        exec.prologue::prepare_transaction
+
+        # mint the asset
+        push.{asset} exec.faucet::mint
+
+        # assert the minted asset is returned
+        push.{asset} assert_eqw
+
+        # assert the asset was recorded as issued
+        push.{asset} push.{issuance_slot} exec.account::get_map_item
+        push.{asset} assert_eqw
+    end
+    ",
+        asset = prepare_word(&Word::from(asset)),
+        issuance_slot = AccountStorage::FAUCET_ISSUANCE_SLOT,
+    );
+
+    let process = run_faucet_tx(account, block_header, chain, notes, &code);
+    assert!(process.is_ok());
+}
+
+#[test]
+fn test_mint_non_fungible_asset_fails_when_already_issued() {
+    // the asset was issued by the faucet in a previous transaction
+    let (_, block_header, chain, notes) = mock_inputs(AccountStatus::Existing);
+    let asset = non_fungible_asset();
+    let account = mock_non_fungible_faucet(&[asset], &mut assembler());
+
+    let code = format!(
+        "
+    use.miden::sat::faucet
+    use.miden::sat::internal::prologue
+
+    begin
+        # prepare the transaction
+        exec.prologue::prepare_transaction
+
+        # mint the asset again
+        push.{asset} exec.faucet::mint
+    end
+    ",
+        asset = prepare_word(&Word::from(asset)),
+    );
+
+    let process = run_faucet_tx(account, block_header, chain, notes, &code);
+    assert!(process.is_err());
+}
+
+#[test]
+fn test_burn_non_fungible_asset_succeeds() {
+    let asset = non_fungible_asset();
+    let account = mock_non_fungible_faucet(&[asset], &mut assembler());
+    let (account, block_header, chain, notes) =
+        mock_inputs_with_note_assets(account, &[asset.into()]);
+
+    let code = format!(
+        "
+    use.miden::sat::faucet
+    use.miden::sat::internal::account
+    use.miden::sat::internal::prologue
+
+    begin
+        # prepare the transaction
+        exec.prologue::prepare_transaction
+
+        # burn the asset provided by the consumed note
+        push.{asset} exec.faucet::burn
+
+        # assert the burned asset is returned
+        push.{asset} assert_eqw
+
+        # assert the asset is no longer recorded as issued
+        push.{asset} push.{issuance_slot} exec.account::get_map_item
+        padw assert_eqw
+    end
+    ",
+        asset = prepare_word(&Word::from(asset)),
+        issuance_slot = AccountStorage::FAUCET_ISSUANCE_SLOT,
+    );
+
+    let process = run_faucet_tx(account, block_header, chain, notes, &code);
+    assert!(process.is_ok());
+}
+
+#[test]
+fn test_burn_non_fungible_asset_fails_when_not_issued() {
+    let asset = non_fungible_asset();
+    let account = mock_non_fungible_faucet(&[], &mut assembler());
+    let (account, block_header, chain, notes) =
+        mock_inputs_with_note_assets(account, &[asset.into()]);
+
+    let code = format!(
+        "
+    use.miden::sat::faucet
+    use.miden::sat::internal::prologue
+
+    begin
+        # prepare the transaction
+        exec.prologue::prepare_transaction
+
+        # burn an asset which was not issued by the faucet
+        push.{asset} exec.faucet::burn
+    end
+    ",
+        asset = prepare_word(&Word::from(asset)),
+    );
+
+    let process = run_faucet_tx(account, block_header, chain, notes, &code);
+    assert!(process.is_err());
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn non_fungible_asset() -> NonFungibleAsset {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let details = NonFungibleAssetDetails::new(faucet_id, NON_FUNGIBLE_ASSET_DATA.to_vec());
+    NonFungibleAsset::new(&details.unwrap()).unwrap()
+}
+
+fn run_faucet_tx(
+    account: Account,
+    block_header: BlockHeader,
+    chain: ChainMmr,
+    notes: Vec<Note>,
+    code: &str,
+) -> Result<Process<MemAdviceProvider>, ExecutionError> {
+    let transaction =
+        prepare_transaction(account, None, block_header, chain, notes, code, "", None, None);
+
+    run_tx(
+        transaction.tx_program().clone(),
+        StackInputs::from(transaction.stack_inputs()),
+        MemAdviceProvider::from(transaction.advice_provider_inputs()),
+    )
+}
@@ -1,7 +1,7 @@
 pub mod common;
 use common::{
     consumed_note_data_ptr,
-    data::{mock_inputs, AccountStatus, ACCOUNT_SEED_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN},
+    data::{mock_inputs, AccountStatus, ACCOUNT_SEED_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN},
     memory::{
         ACCT_CODE_ROOT_PTR, ACCT_DB_ROOT_PTR, ACCT_ID_AND_NONCE_PTR, ACCT_ID_PTR,
         ACCT_STORAGE_ROOT_PTR, ACCT_VAULT_ROOT_PTR, BATCH_ROOT_PTR, BLK_HASH_PTR,
@@ -279,7 +279,7 @@ pub fn test_prologue_create_account() {
     end
     ";
 
-    let account_seed: Word = ACCOUNT_SEED_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN
+    let account_seed: Word = ACCOUNT_SEED_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN
         .iter()
         .map(|x| Felt::new(*x))
         .collect::<Vec<_>>()
@@ -319,7 +319,7 @@ pub fn test_prologue_create_account_invalid_seed() {
     ";
 
     // we must provide a valid seed to `prepare_transaction` otherwise it will error
-    let account_seed: Word = ACCOUNT_SEED_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN
+    let account_seed: Word = ACCOUNT_SEED_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN
         .iter()
         .map(|x| Felt::new(*x))
         .collect::<Vec<_>>()
@@ -1,12 +1,13 @@
 pub mod common;
 use common::{
-    data::ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+    data::{ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN},
     memory::{
         CREATED_NOTE_ASSETS_OFFSET, CREATED_NOTE_METADATA_OFFSET, CREATED_NOTE_RECIPIENT_OFFSET,
         CREATED_NOTE_SECTION_OFFSET, NUM_CREATED_NOTES_PTR,
     },
     procedures::prepare_word,
-    run_within_tx_kernel, Felt, MemAdviceProvider, StackInputs, ONE, ZERO,
+    run_within_tx_kernel, AccountId, Felt, MemAdviceProvider, NonFungibleAsset,
+    NonFungibleAssetDetails, StackInputs, Word, ONE, ZERO,
 };
 
 #[test]
@@ -76,6 +77,50 @@ fn test_create_note() {
     assert_eq!(process.stack.get(0), Felt::new(10000));
 }
 
+#[test]
+fn test_create_note_with_invalid_tag_fails() {
+    let recipient = [ZERO, ONE, Felt::new(2), Felt::new(3)];
+    let asset = [Felt::new(10), ZERO, ZERO, Felt::new(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN)];
+
+    let invalid_tags = [
+        // the tag is not a 32-bit value
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+        // the tag targets an account with an invalid storage mode
+        0b0000_1000 << 24,
+        // the tag requests network execution against a private account
+        0b1000_1100 << 24,
+    ];
+
+    for tag in invalid_tags {
+        let code = format!(
+            "
+        use.miden::sat::tx
+
+        begin
+            push.{recipient}
+            push.{tag}
+            push.{asset}
+
+            exec.tx::create_note
+        end
+        ",
+            recipient = prepare_word(&recipient),
+            asset = prepare_word(&asset)
+        );
+
+        let process = run_within_tx_kernel(
+            "",
+            &code,
+            StackInputs::default(),
+            MemAdviceProvider::default(),
+            None,
+            None,
+        );
+
+        assert!(process.is_err());
+    }
+}
+
 #[test]
 fn test_create_note_too_many_notes() {
     let recipient = [ZERO, ONE, Felt::new(2), Felt::new(3)];
@@ -116,3 +161,112 @@ fn test_create_note_too_many_notes() {
     // assert the process failed
     assert!(process.is_err());
 }
+
+#[test]
+fn test_add_asset_to_note() {
+    let recipient = [ZERO, ONE, Felt::new(2), Felt::new(3)];
+    let tag = Felt::new(4);
+    let asset_0 = [Felt::new(10), ZERO, ZERO, Felt::new(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN)];
+    let asset_1 = [Felt::new(5), ZERO, ZERO, Felt::new(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN)];
+
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let details = NonFungibleAssetDetails::new(faucet_id, vec![1, 2, 3]).unwrap();
+    let asset_2: Word = NonFungibleAsset::new(&details).unwrap().into();
+
+    let code = format!(
+        "
+    use.miden::sat::tx
+
+    begin
+        push.{recipient}
+        push.{tag}
+        push.{asset_0}
+
+        exec.tx::create_note
+        # => [note_ptr]
+
+        # add a fungible asset issued by the same faucet
+        push.{asset_1} movup.4
+        exec.tx::add_asset_to_note
+        # => [note_ptr]
+
+        # add a non-fungible asset
+        push.{asset_2} movup.4
+        exec.tx::add_asset_to_note
+        # => [note_ptr]
+    end
+    ",
+        recipient = prepare_word(&recipient),
+        tag = tag,
+        asset_0 = prepare_word(&asset_0),
+        asset_1 = prepare_word(&asset_1),
+        asset_2 = prepare_word(&asset_2),
+    );
+
+    let process = run_within_tx_kernel(
+        "",
+        &code,
+        StackInputs::default(),
+        MemAdviceProvider::default(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // assert the number of assets in the metadata has been updated.
+    assert_eq!(
+        process
+            .get_memory_value(0, CREATED_NOTE_SECTION_OFFSET + CREATED_NOTE_METADATA_OFFSET)
+            .unwrap(),
+        [Felt::new(2), tag, ZERO, ZERO]
+    );
+
+    // assert the fungible assets have been merged.
+    assert_eq!(
+        process
+            .get_memory_value(0, CREATED_NOTE_SECTION_OFFSET + CREATED_NOTE_ASSETS_OFFSET)
+            .unwrap(),
+        [Felt::new(15), ZERO, ZERO, Felt::new(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN)]
+    );
+
+    // assert the non-fungible asset has been appended.
+    assert_eq!(
+        process
+            .get_memory_value(0, CREATED_NOTE_SECTION_OFFSET + CREATED_NOTE_ASSETS_OFFSET + 1)
+            .unwrap(),
+        asset_2
+    );
+
+    // assert the top item on the stack is a pointer to the created note.
+    assert_eq!(process.stack.get(0), Felt::new(10000));
+}
+
+#[test]
+fn test_add_asset_to_note_invalid_note_ptr() {
+    let asset = [Felt::new(10), ZERO, ZERO, Felt::new(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN)];
+
+    let code = format!(
+        "
+    use.miden::sat::tx
+
+    begin
+        # no note has been created yet
+        push.{asset} push.10000
+        exec.tx::add_asset_to_note
+    end
+    ",
+        asset = prepare_word(&asset)
+    );
+
+    let process = run_within_tx_kernel(
+        "",
+        &code,
+        StackInputs::default(),
+        MemAdviceProvider::default(),
+        None,
+        None,
+    );
+
+    // assert the process failed
+    assert!(process.is_err());
+}
@@ -0,0 +1,86 @@
+use super::{AccountId, AuthenticatorError, Felt, Vec, Word};
+
+#[cfg(feature = "std")]
+use crypto::dsa::rpo_falcon512::{KeyPair, Polynomial, PublicKey};
+
+// TRANSACTION AUTHENTICATOR
+// ================================================================================================
+
+/// The [TransactionAuthenticator] trait defines the interface used by the transaction executor to
+/// obtain signatures required by account authentication procedures.
+///
+/// Account authentication procedures (e.g., `miden::wallets::basic::auth_tx`) expect the signature
+/// to be supplied non-deterministically via the advice map, keyed by the message being signed.
+/// When such a signature is missing, the executor requests it from the authenticator, inserts it
+/// into the advice map, and executes the transaction again.
+///
+/// The executor only requests the signature of the authentication message of the transaction,
+/// i.e., a message whose pre-image the authentication procedure inserted into the advice map and
+/// which commits to the account, its nonce, and the input and output notes of the transaction.
+/// Other missing advice map keys, such as ones requested by note scripts, are never signed, and at
+/// most one message is signed per transaction.
+pub trait TransactionAuthenticator {
+    /// Returns the signature of the specified message on behalf of the specified account.
+    ///
+    /// The signature must be encoded as a list of field elements in the order in which the
+    /// signature verification procedure expects them to be on the advice stack.
+    fn get_signature(
+        &self,
+        account_id: AccountId,
+        message: Word,
+    ) -> Result<Vec<Felt>, AuthenticatorError>;
+}
+
+// SOFTWARE KEY AUTHENTICATOR
+// ================================================================================================
+
+/// A [TransactionAuthenticator] which signs messages with an in-memory RPO Falcon 512 key pair.
+///
+/// This authenticator holds the secret key in memory and is intended primarily for testing.
+#[cfg(feature = "std")]
+pub struct SoftwareKeyAuthenticator {
+    key_pair: KeyPair,
+}
+
+#[cfg(feature = "std")]
+impl SoftwareKeyAuthenticator {
+    /// Returns a new [SoftwareKeyAuthenticator] instantiated with the specified key pair.
+    pub fn new(key_pair: KeyPair) -> Self {
+        Self { key_pair }
+    }
+
+    /// Returns the public key of the key pair used by this authenticator.
+    pub fn public_key(&self) -> PublicKey {
+        self.key_pair.public_key()
+    }
+}
+
+#[cfg(feature = "std")]
+impl TransactionAuthenticator for SoftwareKeyAuthenticator {
+    /// Signs the message with the underlying key pair and returns the data required by
+    /// `std::crypto::dsa::rpo_falcon512::verify`, i.e. the signature nonce, the expanded public
+    /// key, the signature polynomial, and the product of the latter two.
+    fn get_signature(
+        &self,
+        _account_id: AccountId,
+        message: Word,
+    ) -> Result<Vec<Felt>, AuthenticatorError> {
+        let signature = self
+            .key_pair
+            .sign(message)
+            .map_err(AuthenticatorError::SignatureGenerationFailed)?;
+
+        let nonce = signature.nonce();
+        let s2 = signature.sig_poly();
+        let h = self.key_pair.expanded_public_key();
+        let pi = Polynomial::mul_modulo_p(&h, &s2);
+
+        let mut result: Vec<Felt> = nonce.to_vec();
+        result.extend(h.inner().iter().map(|a| Felt::from(*a)));
+        result.extend(s2.inner().iter().map(|a| Felt::from(*a)));
+        result.extend(pi.iter().map(|a| Felt::new(*a)));
+        result.reverse();
+
+        Ok(result)
+    }
+}
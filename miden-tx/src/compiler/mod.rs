@@ -23,6 +23,7 @@ mod tests;
 pub struct TransactionComplier {
     assembler: Assembler,
     account_procedures: BTreeMap<AccountId, Vec<Digest>>,
+    procedure_names: BTreeMap<AccountId, BTreeMap<[u8; 32], String>>,
     prologue: CodeBlock,
     epilogue: CodeBlock,
     note_setup: CodeBlock,
@@ -85,6 +86,7 @@ impl TransactionComplier {
         TransactionComplier {
             assembler,
             account_procedures: BTreeMap::default(),
+            procedure_names: BTreeMap::default(),
             prologue,
             epilogue,
             note_setup,
@@ -97,24 +99,57 @@ impl TransactionComplier {
 
     /// Compiles the provided module into [AccountCode] and associates the resulting procedures
     /// with the specified account ID.
+    ///
+    /// The names of the account procedures are retained to describe the procedures missing from
+    /// account interfaces when note scripts are incompatible with them.
     pub fn load_account(
         &mut self,
         account_id: AccountId,
         account_code: ModuleAst,
     ) -> Result<AccountCode, TransactionCompilerError> {
-        let account_code = AccountCode::new(account_id, account_code, &self.assembler)
-            .map_err(TransactionCompilerError::LoadAccountFailed)?;
-        self.account_procedures.insert(account_id, account_code.procedures().to_vec());
+        let account_code = self.compile_account_code(account_id, account_code)?;
+        self.load_account_code(account_id, &account_code);
         Ok(account_code)
     }
 
+    /// Associates the procedures of the provided [AccountCode] with the specified account ID,
+    /// replacing the interface and the procedure names previously associated with the account.
+    ///
+    /// Procedure names are qualified by the name of the account module, e.g.
+    /// `account_0x...::receive_asset`.
+    pub fn load_account_code(&mut self, account_id: AccountId, account_code: &AccountCode) {
+        let module_path = AccountCode::module_path(account_id);
+        let module_name = module_path.rsplit("::").next().expect("module path is not empty");
+        let procedure_names = account_code
+            .procedure_names()
+            .map(|(name, root)| (root.as_bytes(), format!("{module_name}::{name}")))
+            .collect();
+
+        self.account_procedures.insert(account_id, account_code.procedures().to_vec());
+        self.procedure_names.insert(account_id, procedure_names);
+    }
+
+    /// Compiles the provided module into [AccountCode] for the specified account ID without
+    /// associating the resulting procedures with the account.
+    pub fn compile_account_code(
+        &self,
+        account_id: AccountId,
+        account_code: ModuleAst,
+    ) -> Result<AccountCode, TransactionCompilerError> {
+        AccountCode::new(account_id, account_code, &self.assembler)
+            .map_err(TransactionCompilerError::LoadAccountFailed)
+    }
+
     /// Loads the provided account interface (vector of procedure digests) into the this compiler.
     /// Returns the old account interface if it previously existed.
+    ///
+    /// The procedure names previously associated with the account are discarded.
     pub fn load_account_interface(
         &mut self,
         account_id: AccountId,
         procedures: Vec<Digest>,
     ) -> Option<Vec<Digest>> {
+        self.procedure_names.remove(&account_id);
         self.account_procedures.insert(account_id, procedures)
     }
 
@@ -128,12 +163,19 @@ impl TransactionComplier {
         let (note_script, code_block) = NoteScript::new(note_script_ast, &self.assembler)
             .map_err(|_| TransactionCompilerError::CompileNoteScriptFailed)?;
         for note_target in target_account_proc.into_iter() {
+            let target_account_id = match note_target {
+                NoteTarget::AccountId(id) => Some(id),
+                NoteTarget::Procedures(_) => None,
+            };
             verify_program_account_compatibility(
                 &code_block,
                 &self.get_target_interface(note_target)?,
             )
-            .map_err(|_| {
-                TransactionCompilerError::NoteIncompatibleWithAccountInterface(code_block.hash())
+            .map_err(|missing_procedures| {
+                TransactionCompilerError::NoteIncompatibleWithAccountInterface(
+                    code_block.hash(),
+                    self.procedure_names_of(target_account_id, &missing_procedures),
+                )
             })?;
         }
 
@@ -171,6 +213,7 @@ impl TransactionComplier {
 
         // Create note tree and note [CodeBlock]s
         let (note_tree_root, note_roots) = self.compile_and_build_note_program_tree(
+            account_id,
             &target_account_interface,
             notes,
             &mut assembly_context,
@@ -229,6 +272,7 @@ impl TransactionComplier {
     /// contains the [CodeBlock]s associated with the notes.
     fn compile_and_build_note_program_tree(
         &mut self,
+        target_account_id: AccountId,
         target_account_interface: &[Digest],
         notes: &[Note],
         assembly_context: &mut AssemblyContext,
@@ -244,8 +288,11 @@ impl TransactionComplier {
                 .compile_in_context(note.script().code(), assembly_context)
                 .map_err(|_| TransactionCompilerError::CompileNoteScriptFailed)?;
             verify_program_account_compatibility(&note_root, target_account_interface).map_err(
-                |_| {
-                    TransactionCompilerError::NoteIncompatibleWithAccountInterface(note_root.hash())
+                |missing_procedures| {
+                    TransactionCompilerError::NoteIncompatibleWithAccountInterface(
+                        note_root.hash(),
+                        self.procedure_names_of(Some(target_account_id), &missing_procedures),
+                    )
                 },
             )?;
             note_script_hashes.push(note_root.hash());
@@ -305,6 +352,30 @@ impl TransactionComplier {
         }
     }
 
+    /// Returns the names of the specified procedures as exported by the code of the specified
+    /// account. Procedures which are not exported by the account, or whose account is not known,
+    /// are described by their hex-encoded MAST roots.
+    fn procedure_names_of(
+        &self,
+        account_id: Option<AccountId>,
+        procedures: &[Digest],
+    ) -> Vec<String> {
+        let names = account_id.and_then(|account_id| self.procedure_names.get(&account_id));
+        procedures
+            .iter()
+            .map(|root| {
+                let name = names.and_then(|names| names.get(&root.as_bytes()));
+                match name {
+                    Some(name) => name.clone(),
+                    None => root.as_bytes().iter().fold("0x".to_string(), |mut hex, byte| {
+                        hex.push_str(&format!("{byte:02x}"));
+                        hex
+                    }),
+                }
+            })
+            .collect()
+    }
+
     /// Returns a [CodeBlock] which represents the transaction program.
     fn build_transaction_program(
         &self,
@@ -377,24 +448,29 @@ impl Default for TransactionComplier {
 /// with the target account interface.
 ///
 /// # Errors
-/// Returns an error if the note script is not compatible with the target account interface.
+/// Returns the procedures missing from the target account interface in the execution branch
+/// which is closest to being compatible with it, if the program is not compatible with the target
+/// account interface.
 fn verify_program_account_compatibility(
     program: &CodeBlock,
     target_account_interface: &[Digest],
-) -> Result<(), TransactionCompilerError> {
+) -> Result<(), Vec<Digest>> {
     // collect call branches
     let branches = collect_call_branches(program);
 
+    // collect the procedures missing from the target account interface in each branch
+    let missing_procedures = branches.into_iter().map(|call_targets| {
+        call_targets
+            .into_iter()
+            .filter(|target| !target_account_interface.contains(target))
+            .collect::<Vec<_>>()
+    });
+
     // if none of the branches are compatible with the target account, return an error
-    if !branches.iter().any(|call_targets| {
-        call_targets.iter().all(|target| target_account_interface.contains(target))
-    }) {
-        return Err(TransactionCompilerError::ProgramIncompatibleWithAccountInterface(
-            program.hash(),
-        ));
+    match missing_procedures.min_by_key(|missing| missing.len()) {
+        Some(missing) if !missing.is_empty() => Err(missing),
+        _ => Ok(()),
     }
-
-    Ok(())
 }
 
 /// Collect call branches by recursively traversing through program execution branches and
@@ -458,6 +534,7 @@ pub enum NoteTarget {
 pub struct TransactionComplier {
     pub assembler: Assembler,
     pub account_procedures: BTreeMap<AccountId, Vec<Digest>>,
+    pub procedure_names: BTreeMap<AccountId, BTreeMap<[u8; 32], String>>,
     pub prologue: CodeBlock,
     pub epilogue: CodeBlock,
     pub note_setup: CodeBlock,
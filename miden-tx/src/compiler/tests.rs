@@ -1,13 +1,19 @@
-use super::{AccountId, ModuleAst, Note, NoteTarget, Operation, ProgramAst, TransactionComplier};
+use super::{
+    AccountId, ModuleAst, Note, NoteTarget, Operation, ProgramAst, TransactionCompilerError,
+    TransactionComplier,
+};
 use assembly::AssemblyContext;
-use crypto::{Felt, FieldElement, Word};
+use crypto::{Felt, Word};
 use miden_core::code_blocks::CodeBlock;
-use miden_objects::assets::{Asset, FungibleAsset};
+use miden_objects::{
+    assets::{Asset, FungibleAsset},
+    notes::NoteTag,
+};
 
 // CONSTANTS
 // ================================================================================================
 
-const ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN: u64 = 0b0110011011u64 << 54;
+const ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN: u64 = 0b0100110111u64 << 54;
 
 // Mast roots of account procedures:
 const ACCT_PROC_1: &'static str =
@@ -112,10 +118,10 @@ fn test_compile_valid_note_script() {
     let target_account_proc = NoteTarget::AccountId(account_id);
 
     // TODO: replace this with anonymous call targets once they are implemented
-    let account_id =
+    let additional_account_id =
         AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN + 1).unwrap();
     let account_code_ast = ModuleAst::parse(ADDITIONAL_PROCEDURES).unwrap();
-    tx_compiler.load_account(account_id, account_code_ast).unwrap();
+    tx_compiler.load_account(additional_account_id, account_code_ast).unwrap();
 
     for (note_script_src, expected) in test_cases {
         let note_script_ast = ProgramAst::parse(note_script_src.as_str()).unwrap();
@@ -129,13 +135,82 @@ fn test_compile_valid_note_script() {
     }
 }
 
+#[test]
+fn test_incompatible_note_script_reports_missing_procedures() {
+    let mut tx_compiler = TransactionComplier::new();
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+    let account_code_ast = ModuleAst::parse(ACCOUNT_CODE_MASM).unwrap();
+    tx_compiler.load_account(account_id, account_code_ast).unwrap();
+
+    let additional_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN + 1).unwrap();
+    let account_code_ast = ModuleAst::parse(ADDITIONAL_PROCEDURES).unwrap();
+    tx_compiler.load_account(additional_account_id, account_code_ast).unwrap();
+
+    // procedures exported by other loaded accounts are not reported by their names, as the names
+    // are only meaningful within the code of those accounts
+    let note_script_ast = ProgramAst::parse(&format!(
+        "begin
+            call.{ACCT_PROC_1}
+            call.{ADD_PROC_1}
+        end"
+    ))
+    .unwrap();
+    let result =
+        tx_compiler.compile_note_script(note_script_ast, vec![NoteTarget::AccountId(account_id)]);
+    let err = result.unwrap_err();
+    assert!(err.to_string().ends_with(&format!("missing {ADD_PROC_1}")));
+    match err {
+        TransactionCompilerError::NoteIncompatibleWithAccountInterface(_, missing) => {
+            assert_eq!(missing, vec![ADD_PROC_1.to_string()])
+        }
+        _ => panic!("expected the note script to be incompatible with the account interface"),
+    }
+
+    // names of procedures which are no longer part of a reloaded account are not reported
+    let account_code_ast = ModuleAst::parse(ACCOUNT_CODE_MASM).unwrap();
+    tx_compiler.load_account(additional_account_id, account_code_ast).unwrap();
+    let note_script_ast = ProgramAst::parse(&format!(
+        "begin
+            call.{ADD_PROC_1}
+        end"
+    ))
+    .unwrap();
+    let result =
+        tx_compiler.compile_note_script(note_script_ast, vec![NoteTarget::Procedures(vec![])]);
+    match result {
+        Err(TransactionCompilerError::NoteIncompatibleWithAccountInterface(_, missing)) => {
+            assert_eq!(missing, vec![ADD_PROC_1.to_string()])
+        }
+        _ => panic!("expected the note script to be incompatible with the account interface"),
+    }
+
+    // procedures which are not known to the compiler are reported by their MAST roots
+    let mut tx_compiler = TransactionComplier::new();
+    let note_script_ast = ProgramAst::parse(&format!(
+        "begin
+            call.{ACCT_PROC_1}
+        end"
+    ))
+    .unwrap();
+    let result =
+        tx_compiler.compile_note_script(note_script_ast, vec![NoteTarget::Procedures(vec![])]);
+    match result {
+        Err(TransactionCompilerError::NoteIncompatibleWithAccountInterface(_, missing)) => {
+            assert_eq!(missing, vec![ACCT_PROC_1.to_string()])
+        }
+        _ => panic!("expected the note script to be incompatible with the account interface"),
+    }
+}
+
 fn mock_consumed_notes(
     tx_compiler: &mut TransactionComplier,
     target_account: AccountId,
 ) -> Vec<Note> {
-    pub const ACCOUNT_ID_SENDER: u64 = 0b0110111011u64 << 54;
+    pub const ACCOUNT_ID_SENDER: u64 = 0b0100111011u64 << 54;
 
-    pub const ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN: u64 = 0b1010011100 << 54;
+    pub const ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN: u64 = 0b1000111100 << 54;
     // Note Assets
     let faucet_id_1 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
     let faucet_id_2 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN + 10).unwrap();
@@ -162,7 +237,7 @@ fn mock_consumed_notes(
         &[fungible_asset_1, fungible_asset_2, fungible_asset_3],
         SERIAL_NUM_1,
         sender,
-        Felt::ZERO,
+        NoteTag::try_from(0).unwrap(),
         None,
     )
     .unwrap();
@@ -174,7 +249,7 @@ fn mock_consumed_notes(
         &[fungible_asset_1, fungible_asset_2, fungible_asset_3],
         SERIAL_NUM_2,
         sender,
-        Felt::ZERO,
+        NoteTag::try_from(0).unwrap(),
         None,
     )
     .unwrap();
@@ -1,19 +1,36 @@
 use super::{
-    Account, AccountId, BlockHeader, ChainMmr, DataStoreError, ModuleAst, Note, NoteOrigin,
+    Account, AccountCode, AccountId, BlockHeader, ChainMmr, DataStoreError, ModuleAst, Note,
+    NoteOrigin, Word,
 };
 
 /// The [DataStore] trait defines the interface that transaction objects use to fetch data
 /// required for transaction execution.
 pub trait DataStore {
-    /// Returns the [Account], [BlockHeader], [ChainMmr], and [Note]s required for transaction
-    /// execution.
+    /// Returns the [Account], account seed, [BlockHeader], [ChainMmr], and [Note]s required for
+    /// transaction execution.
+    ///
+    /// The account seed must be provided if the account is new (i.e. it has not been initialized
+    /// yet), and should be `None` otherwise.
+    #[allow(clippy::type_complexity)]
     fn get_transaction_data(
         &self,
         account_id: AccountId,
         block_num: u32,
         notes: &[NoteOrigin],
-    ) -> Result<(Account, BlockHeader, ChainMmr, Vec<Note>), DataStoreError>;
+    ) -> Result<(Account, Option<Word>, BlockHeader, ChainMmr, Vec<Note>), DataStoreError>;
 
     /// Returns the account code [ModuleAst] associated with the the specified [AccountId].
     fn get_account_code(&self, account_id: AccountId) -> Result<ModuleAst, DataStoreError>;
+
+    /// Replaces the account code associated with the specified [AccountId] with the provided
+    /// [AccountCode].
+    ///
+    /// This is invoked by the transaction executor after executing a transaction which updated the
+    /// code of the account. The provided code includes the metadata of the account procedures, and
+    /// its root is the code root the account was updated to.
+    fn update_account_code(
+        &mut self,
+        account_id: AccountId,
+        account_code: AccountCode,
+    ) -> Result<(), DataStoreError>;
 }
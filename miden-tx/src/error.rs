@@ -1,8 +1,9 @@
 use super::{
     AccountError, AccountId, AssemblyError, Digest, ExecutionError, NodeIndex,
-    TransactionResultError,
+    TransactionResultError, Word,
 };
 use core::fmt;
+use crypto::dsa::rpo_falcon512::FalconError;
 use miden_objects::{PreparedTransactionError, TransactionWitnessError};
 use miden_verifier::VerificationError;
 
@@ -16,8 +17,16 @@ pub enum TransactionError {
 }
 
 impl fmt::Display for TransactionError {
-    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        todo!()
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TransactionError::TransactionCompilerError(err) => {
+                write!(f, "transaction compiler error: {err}")
+            }
+            TransactionError::TransactionExecutorError(err) => {
+                write!(f, "transaction executor error: {err}")
+            }
+            TransactionError::DataStoreError(err) => write!(f, "data store error: {err}"),
+        }
     }
 }
 
@@ -31,8 +40,7 @@ pub enum TransactionCompilerError {
     InvalidTransactionInputs,
     LoadAccountFailed(AccountError),
     AccountInterfaceNotFound(AccountId),
-    ProgramIncompatibleWithAccountInterface(Digest),
-    NoteIncompatibleWithAccountInterface(Digest),
+    NoteIncompatibleWithAccountInterface(Digest, Vec<String>),
     TxScriptIncompatibleWithAccountInterface(Digest),
     CompileNoteScriptFailed,
     CompileTxScriptFailed(AssemblyError),
@@ -40,8 +48,28 @@ pub enum TransactionCompilerError {
 }
 
 impl fmt::Display for TransactionCompilerError {
-    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        todo!()
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use TransactionCompilerError::*;
+        match self {
+            InvalidTransactionInputs => write!(f, "invalid transaction inputs"),
+            LoadAccountFailed(err) => write!(f, "failed to load account: {err:?}"),
+            AccountInterfaceNotFound(account_id) => {
+                write!(f, "interface of account {account_id} not found")
+            }
+            NoteIncompatibleWithAccountInterface(root, missing) => write!(
+                f,
+                "note script {root:?} is incompatible with the account interface: missing {}",
+                missing.join(", ")
+            ),
+            TxScriptIncompatibleWithAccountInterface(root) => {
+                write!(f, "transaction script {root:?} is incompatible with the account interface")
+            }
+            CompileNoteScriptFailed => write!(f, "failed to compile note script"),
+            CompileTxScriptFailed(err) => write!(f, "failed to compile transaction script: {err}"),
+            BuildCodeBlockTableFailed(err) => {
+                write!(f, "failed to build the code block table: {err}")
+            }
+        }
     }
 }
 
@@ -56,21 +84,71 @@ pub enum TransactionExecutorError {
     CompileTransactionError(TransactionCompilerError),
     ConstructPreparedTransactionFailed(PreparedTransactionError),
     ExecuteTransactionProgramFailed(ExecutionError),
+    AuthenticationFailed(AuthenticatorError),
+    InvalidSignatureRequest(Word),
+    AccountCodeNotUpgraded(Digest),
     FetchAccountCodeFailed(DataStoreError),
     FetchTransactionDataFailed(DataStoreError),
     LoadAccountFailed(TransactionCompilerError),
     TransactionResultError(TransactionResultError),
+    UpdateAccountCodeFailed(DataStoreError),
 }
 
 impl fmt::Display for TransactionExecutorError {
-    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        todo!()
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use TransactionExecutorError::*;
+        match self {
+            CompileNoteScriptFailed(err) => write!(f, "failed to compile note script: {err}"),
+            CompileTransactionError(err) => write!(f, "failed to compile transaction: {err}"),
+            ConstructPreparedTransactionFailed(err) => {
+                write!(f, "failed to construct prepared transaction: {err:?}")
+            }
+            ExecuteTransactionProgramFailed(err) => {
+                write!(f, "failed to execute transaction program: {err}")
+            }
+            AuthenticationFailed(err) => write!(f, "failed to authenticate transaction: {err}"),
+            InvalidSignatureRequest(message) => write!(
+                f,
+                "signed message {message:?} does not commit to the notes created by the transaction"
+            ),
+            AccountCodeNotUpgraded(root) => {
+                write!(f, "transaction did not update the account code to code with root {root:?}")
+            }
+            FetchAccountCodeFailed(err) => write!(f, "failed to fetch account code: {err}"),
+            FetchTransactionDataFailed(err) => {
+                write!(f, "failed to fetch transaction data: {err}")
+            }
+            LoadAccountFailed(err) => write!(f, "failed to load account: {err}"),
+            TransactionExecutorError::TransactionResultError(err) => {
+                write!(f, "failed to construct transaction result: {err:?}")
+            }
+            UpdateAccountCodeFailed(err) => write!(f, "failed to update account code: {err}"),
+        }
     }
 }
 
 #[cfg(feature = "std")]
 impl std::error::Error for TransactionExecutorError {}
 
+// AUTHENTICATOR ERROR
+// ================================================================================================
+#[derive(Debug)]
+pub enum AuthenticatorError {
+    SignatureGenerationFailed(FalconError),
+}
+
+impl fmt::Display for AuthenticatorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use AuthenticatorError::*;
+        match self {
+            SignatureGenerationFailed(err) => write!(f, "failed to generate signature: {err:?}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AuthenticatorError {}
+
 // TRANSACTION PROVER ERROR
 // ================================================================================================
 #[derive(Debug)]
@@ -81,8 +159,19 @@ pub enum TransactionProverError {
 }
 
 impl fmt::Display for TransactionProverError {
-    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        todo!()
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use TransactionProverError::*;
+        match self {
+            ProveTransactionProgramFailed(err) => {
+                write!(f, "failed to prove transaction program: {err}")
+            }
+            TransactionProverError::TransactionResultError(err) => {
+                write!(f, "failed to construct transaction result: {err:?}")
+            }
+            CorruptTransactionWitnessConsumedNoteData(err) => {
+                write!(f, "transaction witness contains corrupt consumed note data: {err:?}")
+            }
+        }
     }
 }
 
@@ -98,8 +187,17 @@ pub enum TransactionVerifierError {
 }
 
 impl fmt::Display for TransactionVerifierError {
-    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        todo!()
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use TransactionVerifierError::*;
+        match self {
+            TransactionVerificationFailed(err) => {
+                write!(f, "failed to verify transaction: {err:?}")
+            }
+            InsufficientProofSecurityLevel(actual, expected) => write!(
+                f,
+                "proof security level of {actual} bits is below the required {expected} bits"
+            ),
+        }
     }
 }
 
@@ -115,8 +213,14 @@ pub enum DataStoreError {
 }
 
 impl fmt::Display for DataStoreError {
-    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        todo!()
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use DataStoreError::*;
+        match self {
+            AccountNotFound(account_id) => write!(f, "account {account_id} not found"),
+            NoteNotFound(block_num, note_index) => {
+                write!(f, "note {note_index:?} of block {block_num} not found")
+            }
+        }
     }
 }
 
@@ -1,8 +1,13 @@
 use super::{
-    AccountCode, AccountId, DataStore, Digest, NoteOrigin, NoteScript, NoteTarget,
-    PreparedTransaction, ProgramAst, RecAdviceProvider, TransactionComplier,
-    TransactionExecutorError, TransactionResult,
+    AccountCode, AccountId, BTreeMap, DataStore, Digest, ExecutionError, Felt, Hasher, ModuleAst,
+    NoteOrigin, NoteScript, NoteTarget, PreparedTransaction, ProgramAst, RecAdviceProvider,
+    TransactionAuthenticator, TransactionComplier, TransactionExecutorError, TransactionResult,
+    Vec, Word, WORD_SIZE, ZERO,
 };
+use miden_core::utils::IntoBytes;
+
+// TRANSACTION EXECUTOR
+// ================================================================================================
 
 /// The transaction executor is responsible for executing Miden rollup transactions.
 ///
@@ -14,12 +19,17 @@ use super::{
 /// The [TransactionExecutor] is generic over the [DataStore] which allows it to be used with
 /// different data backend implementations.
 ///
+/// Signatures required by account authentication procedures are obtained from the
+/// [TransactionAuthenticator] provided via [TransactionExecutor::with_authenticator()].
+///
 /// The [TransactionExecutor::execute_transaction()] method is the main entry point for the
 /// executor and produces a [TransactionWitness] for the transaction. The TransactionWitness can
 /// then be used to by the prover to generate a proof transaction execution.
 pub struct TransactionExecutor<D: DataStore> {
     compiler: TransactionComplier,
     data_store: D,
+    account_code_updates: BTreeMap<Digest, AccountCode>,
+    authenticator: Option<Box<dyn TransactionAuthenticator>>,
 }
 
 impl<D: DataStore> TransactionExecutor<D> {
@@ -31,9 +41,29 @@ impl<D: DataStore> TransactionExecutor<D> {
         Self {
             compiler,
             data_store,
+            account_code_updates: BTreeMap::new(),
+            authenticator: None,
         }
     }
 
+    /// Returns a new [TransactionExecutor] which requests signatures for account authentication
+    /// procedures from the specified [TransactionAuthenticator].
+    pub fn with_authenticator<A>(mut self, authenticator: A) -> Self
+    where
+        A: TransactionAuthenticator + 'static,
+    {
+        self.authenticator = Some(Box::new(authenticator));
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a reference to the [DataStore] used by this executor.
+    pub fn data_store(&self) -> &D {
+        &self.data_store
+    }
+
     // MODIFIERS
     // --------------------------------------------------------------------------------------------
 
@@ -59,6 +89,28 @@ impl<D: DataStore> TransactionExecutor<D> {
             .map_err(TransactionExecutorError::LoadAccountFailed)
     }
 
+    /// Compiles the provided module into [AccountCode] for the specified account and registers it
+    /// as code the account may be updated to via `set_code` in subsequently executed transactions.
+    ///
+    /// When a transaction updates the account code root to the root of the registered code, the
+    /// code is included in the [TransactionResult], and the compiler cache and the [DataStore] are
+    /// updated with the new code.
+    ///
+    /// # Errors:
+    /// Returns an error if the module fails to compile.
+    pub fn load_account_code_update(
+        &mut self,
+        account_id: AccountId,
+        account_code: ModuleAst,
+    ) -> Result<AccountCode, TransactionExecutorError> {
+        let account_code = self
+            .compiler
+            .compile_account_code(account_id, account_code)
+            .map_err(TransactionExecutorError::LoadAccountFailed)?;
+        self.account_code_updates.insert(account_code.root(), account_code.clone());
+        Ok(account_code)
+    }
+
     /// Loads the provided account interface (vector of procedure digests) into the the compiler.
     ///
     /// Returns the old account interface if it previously existed.
@@ -89,11 +141,21 @@ impl<D: DataStore> TransactionExecutor<D> {
     /// and compile the transaction into an executable program. Then it executes the transaction
     /// program and creates a [TransactionWitness].
     ///
+    /// If the execution fails because the signature of the authentication message of the
+    /// transaction is missing from the advice map, the signature is requested from the
+    /// [TransactionAuthenticator], inserted into the advice map under the message, and the
+    /// transaction program is executed again. Signatures of any other messages are never requested.
+    ///
     /// # Errors:
     /// Returns an error if:
     /// - If required data can not be fetched from the [DataStore].
     /// - If the transaction program can not be compiled.
     /// - If the transaction program can not be executed.
+    /// - If a required signature can not be provided by the [TransactionAuthenticator].
+    /// - If the signed message does not commit to the notes created by the transaction.
+    /// - If the account code was updated to code which was not registered via
+    ///   [TransactionExecutor::load_account_code_update()].
+    /// - If the updated account code can not be stored in the [DataStore].
     pub fn execute_transaction(
         &mut self,
         account_id: AccountId,
@@ -101,31 +163,45 @@ impl<D: DataStore> TransactionExecutor<D> {
         note_origins: &[NoteOrigin],
         tx_script: Option<ProgramAst>,
     ) -> Result<TransactionResult, TransactionExecutorError> {
-        let transaction =
-            self.prepare_transaction(account_id, block_ref, note_origins, tx_script)?;
+        let tx_result = self.run_transaction(account_id, block_ref, note_origins, tx_script)?;
+        self.apply_account_code_update(&tx_result)?;
+        Ok(tx_result)
+    }
 
-        let mut advice_recorder: RecAdviceProvider = transaction.advice_provider_inputs().into();
-        let result = processor::execute(
-            transaction.tx_program(),
-            transaction.stack_inputs(),
-            &mut advice_recorder,
-            Default::default(),
-        )
-        .map_err(TransactionExecutorError::ExecuteTransactionProgramFailed)?;
+    /// Executes a transaction which upgrades the code of the account to the provided module and
+    /// returns a [TransactionResult].
+    ///
+    /// The module is compiled and registered as described in
+    /// [TransactionExecutor::load_account_code_update()]. The transaction script is expected to set
+    /// the account code root to the root of the compiled module via `set_code`; the root can be
+    /// obtained in advance from the [AccountCode] returned by
+    /// [TransactionExecutor::load_account_code_update()].
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If the module fails to compile.
+    /// - If the transaction can not be executed as described in
+    ///   [TransactionExecutor::execute_transaction()].
+    /// - If the transaction did not update the account code root to the root of the module.
+    pub fn execute_account_code_upgrade(
+        &mut self,
+        account_id: AccountId,
+        block_ref: u32,
+        note_origins: &[NoteOrigin],
+        tx_script: ProgramAst,
+        account_code: ModuleAst,
+    ) -> Result<TransactionResult, TransactionExecutorError> {
+        let account_code = self.load_account_code_update(account_id, account_code)?;
+        let tx_result =
+            self.run_transaction(account_id, block_ref, note_origins, Some(tx_script))?;
 
-        let (account, block_header, _block_chain, consumed_notes, tx_program, tx_script_root) =
-            transaction.into_parts();
+        match tx_result.updated_account_code() {
+            Some(code) if code.root() == account_code.root() => (),
+            _ => return Err(TransactionExecutorError::AccountCodeNotUpgraded(account_code.root())),
+        }
 
-        TransactionResult::new(
-            account,
-            consumed_notes,
-            block_header.hash(),
-            tx_program,
-            tx_script_root,
-            advice_recorder,
-            result.stack_outputs().clone(),
-        )
-        .map_err(TransactionExecutorError::TransactionResultError)
+        self.apply_account_code_update(&tx_result)?;
+        Ok(tx_result)
     }
 
     /// Fetches the data required to execute the transaction from the [DataStore], compiles the
@@ -143,7 +219,7 @@ impl<D: DataStore> TransactionExecutor<D> {
         note_origins: &[NoteOrigin],
         tx_script: Option<ProgramAst>,
     ) -> Result<PreparedTransaction, TransactionExecutorError> {
-        let (account, block_header, block_chain, notes) = self
+        let (account, account_seed, block_header, block_chain, notes) = self
             .data_store
             .get_transaction_data(account_id, block_ref, note_origins)
             .map_err(TransactionExecutorError::FetchTransactionDataFailed)?;
@@ -155,7 +231,7 @@ impl<D: DataStore> TransactionExecutor<D> {
 
         PreparedTransaction::new(
             account,
-            None,
+            account_seed,
             block_header,
             block_chain,
             notes,
@@ -164,4 +240,155 @@ impl<D: DataStore> TransactionExecutor<D> {
         )
         .map_err(TransactionExecutorError::ConstructPreparedTransactionFailed)
     }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Executes a transaction specified by the provided arguments and returns a
+    /// [TransactionResult] without applying the account code update it may contain.
+    fn run_transaction(
+        &mut self,
+        account_id: AccountId,
+        block_ref: u32,
+        note_origins: &[NoteOrigin],
+        tx_script: Option<ProgramAst>,
+    ) -> Result<TransactionResult, TransactionExecutorError> {
+        let transaction =
+            self.prepare_transaction(account_id, block_ref, note_origins, tx_script)?;
+
+        let mut advice_inputs = transaction.advice_provider_inputs();
+        let mut signature_request = None;
+        let (result, advice_recorder) = loop {
+            let mut advice_recorder: RecAdviceProvider = advice_inputs.clone().into();
+            let err = match processor::execute(
+                transaction.tx_program(),
+                transaction.stack_inputs(),
+                &mut advice_recorder,
+                Default::default(),
+            ) {
+                Ok(result) => break (result, advice_recorder),
+                Err(err) => err,
+            };
+
+            // the only missing advice map key the authenticator is asked about is the signature
+            // of the authentication message of the transaction, which is requested at most once
+            let (authenticator, message) = match (self.authenticator.as_ref(), err) {
+                (Some(authenticator), ExecutionError::AdviceKeyNotFound(message))
+                    if signature_request.is_none() =>
+                {
+                    (authenticator, message)
+                }
+                (_, err) => {
+                    return Err(TransactionExecutorError::ExecuteTransactionProgramFailed(err))
+                }
+            };
+
+            let (_, _, advice_map, _) = advice_recorder.finalize();
+            let output_notes_hash =
+                match validate_signature_request(&transaction, &advice_map, message) {
+                    Some(output_notes_hash) => output_notes_hash,
+                    None => {
+                        return Err(TransactionExecutorError::ExecuteTransactionProgramFailed(
+                            ExecutionError::AdviceKeyNotFound(message),
+                        ))
+                    }
+                };
+
+            let signature = authenticator
+                .get_signature(account_id, message)
+                .map_err(TransactionExecutorError::AuthenticationFailed)?;
+            advice_inputs.extend_map([(message.into_bytes(), signature)]);
+            signature_request = Some((message, output_notes_hash));
+        };
+
+        let account_seed = transaction.account_seed();
+        let (account, block_header, _block_chain, consumed_notes, tx_program, tx_script_root) =
+            transaction.into_parts();
+
+        let tx_result = TransactionResult::new(
+            account,
+            account_seed,
+            &self.account_code_updates,
+            consumed_notes,
+            block_header.hash(),
+            tx_program,
+            tx_script_root,
+            advice_recorder,
+            result.stack_outputs().clone(),
+        )
+        .map_err(TransactionExecutorError::TransactionResultError)?;
+
+        // the output notes of the transaction are only known once it has been executed; the
+        // signed message must commit to them
+        if let Some((message, output_notes_hash)) = signature_request {
+            if tx_result.created_notes().commitment() != output_notes_hash {
+                return Err(TransactionExecutorError::InvalidSignatureRequest(message));
+            }
+        }
+
+        Ok(tx_result)
+    }
+
+    /// Loads the code the account was updated to by the transaction, if any, into the compiler
+    /// cache and the [DataStore].
+    fn apply_account_code_update(
+        &mut self,
+        tx_result: &TransactionResult,
+    ) -> Result<(), TransactionExecutorError> {
+        if let Some(account_code) = tx_result.updated_account_code() {
+            let account_id = tx_result.account_id();
+            self.compiler.load_account_code(account_id, account_code);
+            self.data_store
+                .update_account_code(account_id, account_code.clone())
+                .map_err(TransactionExecutorError::UpdateAccountCodeFailed)?;
+        }
+        Ok(())
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Checks that the specified message is the authentication message of the transaction and returns
+/// the commitment to the output notes the message was computed from.
+///
+/// Account authentication procedures (e.g., `miden::wallets::basic::auth_tx`) insert the pre-image
+/// of the message they request a signature for into the advice map under hash(MESSAGE, 0). The
+/// pre-image consists of `[account_id, 0, 0, 0]`, `[nonce, 0, 0, 0]`, the input notes hash and the
+/// output notes hash, and the message is computed as
+/// hash(OUTPUT_NOTES_HASH, hash(INPUT_NOTES_HASH, hash(NONCE, ACCOUNT_ID))).
+///
+/// Returns None if the pre-image is missing, does not hash to the message, or does not commit to
+/// the account, the nonce and the input notes of the transaction. The output notes are only known
+/// once the transaction has been executed and must be checked by the caller.
+fn validate_signature_request(
+    transaction: &PreparedTransaction,
+    advice_map: &BTreeMap<[u8; 32], Vec<Felt>>,
+    message: Word,
+) -> Option<Digest> {
+    let request_key: Word = Hasher::merge(&[message.into(), Digest::default()]).into();
+    let preimage = advice_map.get(&request_key.into_bytes())?;
+    if preimage.len() != 4 * WORD_SIZE {
+        return None;
+    }
+
+    let words: Vec<Word> = preimage
+        .chunks_exact(WORD_SIZE)
+        .map(|word| [word[0], word[1], word[2], word[3]])
+        .collect();
+    let (account_id, nonce) = (words[0], words[1]);
+    let (input_notes_hash, output_notes_hash) = (Digest::from(words[2]), Digest::from(words[3]));
+
+    let expected_message = Hasher::merge(&[
+        output_notes_hash,
+        Hasher::merge(&[input_notes_hash, Hasher::merge(&[nonce.into(), account_id.into()])]),
+    ]);
+
+    let account = transaction.account();
+    let is_valid = account_id == [account.id().into(), ZERO, ZERO, ZERO]
+        && nonce == [account.nonce(), ZERO, ZERO, ZERO]
+        && input_notes_hash == transaction.consumed_notes_commitment()
+        && Word::from(expected_message) == message;
+
+    is_valid.then_some(output_notes_hash)
 }
@@ -2,8 +2,14 @@ use assembly::{
     ast::{ModuleAst, ProgramAst},
     Assembler, AssemblyContext, AssemblyContextType, AssemblyError,
 };
-use crypto::{hash::rpo::Rpo256 as Hasher, hash::rpo::RpoDigest as Digest, merkle::NodeIndex};
-use miden_core::{code_blocks::CodeBlock, utils::collections::BTreeMap, Operation, Program};
+use crypto::{
+    hash::rpo::Rpo256 as Hasher, hash::rpo::RpoDigest as Digest, merkle::NodeIndex, WORD_SIZE, ZERO,
+};
+use miden_core::{
+    code_blocks::CodeBlock,
+    utils::collections::{BTreeMap, Vec},
+    Felt, Operation, Program, Word,
+};
 use miden_lib::{MidenLib, SatKernel};
 use miden_objects::{
     notes::{Note, NoteOrigin, NoteScript},
@@ -13,13 +19,17 @@ use miden_objects::{
 use miden_stdlib::StdLibrary;
 use processor::{ExecutionError, RecAdviceProvider};
 
+mod auth;
+#[cfg(feature = "std")]
+pub use auth::SoftwareKeyAuthenticator;
+pub use auth::TransactionAuthenticator;
 mod compiler;
 pub use compiler::{NoteTarget, TransactionComplier};
 mod data;
 use data::DataStore;
 mod error;
 mod executor;
-pub use error::TransactionError;
+pub use error::{AuthenticatorError, TransactionError};
 use error::{
     DataStoreError, TransactionCompilerError, TransactionExecutorError, TransactionProverError,
     TransactionVerifierError,
@@ -1,27 +1,44 @@
 use super::{
-    AccountId, BlockHeader, ChainMmr, DataStore, DataStoreError, Note, NoteOrigin,
-    TransactionExecutor, TransactionProver, TransactionVerifier,
+    AccountId, AuthenticatorError, BlockHeader, ChainMmr, DataStore, DataStoreError, Note,
+    NoteOrigin, NoteTarget, SoftwareKeyAuthenticator, TransactionAuthenticator,
+    TransactionExecutor, TransactionExecutorError, TransactionProver, TransactionVerifier,
 };
 use assembly::{
     ast::{ModuleAst, ProgramAst},
     Assembler,
 };
-use crypto::StarkField;
-use miden_core::Felt;
+use crypto::{
+    dsa::rpo_falcon512::KeyPair, merkle::MerkleStore, utils::collections::TryApplyDiff, StarkField,
+};
+use miden_core::{Felt, Word};
 use miden_objects::{
+    assets::{Asset, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
     mock::{
-        mock_inputs, prepare_word, AccountStatus, CHILD_ROOT_PARENT_LEAF_INDEX, CHILD_SMT_DEPTH,
+        assembler, mock_basic_fungible_faucet, mock_basic_wallet, mock_block_header,
+        mock_chain_data, mock_inputs, prepare_word, AccountStatus,
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_3, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+        ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+        ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, ACCOUNT_ID_SENDER,
+        ACCOUNT_SEED_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN, CHILD_ROOT_PARENT_LEAF_INDEX,
         CHILD_STORAGE_INDEX_0,
     },
-    transaction::{CreatedNotes, FinalAccountStub},
-    Account, AccountCode, TryFromVmResult,
+    notes::{
+        standard::{create_p2id_note, create_p2idr_note, create_swap_note},
+        NoteExecutionHint, NoteScript, NoteTag,
+    },
+    transaction::{CreatedNotes, FinalAccountStub, TransactionResult},
+    Account, AccountCode, AccountStorage, AccountStorageMode, AccountVault, StorageMap,
+    TryFromVmResult,
 };
 use miden_prover::ProvingOptions;
-use processor::MemAdviceProvider;
+use processor::{ExecutionError, MemAdviceProvider};
+use std::{cell::RefCell, rc::Rc};
 
 #[derive(Clone)]
 pub struct MockDataStore {
     pub account: Account,
+    pub account_seed: Option<Word>,
     pub block_header: BlockHeader,
     pub block_chain: ChainMmr,
     pub notes: Vec<Note>,
@@ -33,6 +50,21 @@ impl MockDataStore {
             mock_inputs(AccountStatus::Existing);
         Self {
             account,
+            account_seed: None,
+            block_header,
+            block_chain,
+            notes: consumed_notes,
+        }
+    }
+}
+
+impl MockDataStore {
+    pub fn with_new_account() -> Self {
+        let (account, block_header, block_chain, consumed_notes) = mock_inputs(AccountStatus::New);
+        let account_seed = ACCOUNT_SEED_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN.map(Felt::new);
+        Self {
+            account,
+            account_seed: Some(account_seed),
             block_header,
             block_chain,
             notes: consumed_notes,
@@ -52,7 +84,7 @@ impl DataStore for MockDataStore {
         account_id: AccountId,
         block_num: u32,
         notes: &[NoteOrigin],
-    ) -> Result<(Account, BlockHeader, ChainMmr, Vec<Note>), DataStoreError> {
+    ) -> Result<(Account, Option<Word>, BlockHeader, ChainMmr, Vec<Note>), DataStoreError> {
         assert_eq!(account_id, self.account.id());
         assert_eq!(block_num as u64, self.block_header.block_num().as_int());
         assert_eq!(notes.len(), self.notes.len());
@@ -64,6 +96,7 @@ impl DataStore for MockDataStore {
         notes.iter().all(|note| origins.contains(&note));
         Ok((
             self.account.clone(),
+            self.account_seed,
             self.block_header.clone(),
             self.block_chain.clone(),
             self.notes.clone(),
@@ -77,6 +110,22 @@ impl DataStore for MockDataStore {
         assert_eq!(account_id, self.account.id());
         Ok(self.account.code().module().clone())
     }
+
+    fn update_account_code(
+        &mut self,
+        account_id: AccountId,
+        account_code: AccountCode,
+    ) -> Result<(), DataStoreError> {
+        assert_eq!(account_id, self.account.id());
+        self.account = Account::new(
+            account_id,
+            self.account.vault().clone(),
+            self.account.storage().clone(),
+            account_code,
+            self.account.nonce(),
+        );
+        Ok(())
+    }
 }
 
 #[test]
@@ -139,17 +188,16 @@ fn test_transaction_result_account_delta() {
     let tx_script = format!(
         "\
         use.context::account_{account_id}
-        use.miden::sat::account
 
         ## ACCOUNT PROCEDURE WRAPPERS
         ## ========================================================================================
         #TODO: Move this into an account library
-        proc.set_item
-            push.0 movdn.5 push.0 movdn.5 push.0 movdn.5
-            # => [index, V', 0, 0, 0]
+        proc.set_array_item
+            call.account_{account_id}::set_array_item
+            # => [OLD_ARRAY_ROOT, OLD_VALUE]
 
-            call.account_{account_id}::set_item
-            # => [R', V]
+            dropw dropw
+            # => []
         end
 
         proc.set_code
@@ -171,34 +219,13 @@ fn test_transaction_result_account_delta() {
         ## TRANSACTION SCRIPT
         ## ========================================================================================
         begin
-            ## Update account storage child tree
+            ## Update account storage array
             ## ------------------------------------------------------------------------------------
-            # get the current child tree root from account storage slot
-            push.{CHILD_ROOT_PARENT_LEAF_INDEX}
-            # => [idx]
-
-            # get the child root
-            exec.account::get_item
-            # => [CHILD_ROOT]
-
-            # prepare the stack to remove in the child tree
-            padw swapw push.0 push.{CHILD_SMT_DEPTH}
-            # => [depth, idx(push.0), CHILD_ROOT, NEW_VALUE (padw)]
-
-            # set new value and drop old value
-            mtree_set dropw
-            # => [NEW_CHILD_ROOT]
-
-            # prepare stack to delete existing child tree value (replace with empty word)
-            padw swapw push.{CHILD_STORAGE_INDEX_0} push.{CHILD_SMT_DEPTH} 
-            # => [depth, idx, NEW_CHILD_ROOT, EMPTY_WORD]
+            # remove the existing item from the array stored in the account storage slot
+            padw push.{CHILD_STORAGE_INDEX_0} push.{CHILD_ROOT_PARENT_LEAF_INDEX}
+            # => [index, pos, EMPTY_WORD]
 
-            # set existing value to empty word
-            mtree_set dropw
-            # => [NEW_CHILD_ROOT]
-
-            # store the new child root in account storage slot
-            push.{CHILD_ROOT_PARENT_LEAF_INDEX} exec.set_item dropw dropw
+            exec.set_array_item
             # => []
 
             ## Update account code
@@ -218,6 +245,9 @@ fn test_transaction_result_account_delta() {
     let mut executor = TransactionExecutor::new(data_store.clone());
     let account_id = data_store.account.id();
     executor.load_account(account_id).unwrap();
+    executor
+        .load_account_code_update(account_id, new_acct_code_ast.clone())
+        .unwrap();
 
     let block_ref = data_store.block_header.block_num().as_int() as u32;
     let note_origins = data_store
@@ -232,21 +262,690 @@ fn test_transaction_result_account_delta() {
     let transaction_result = executor
         .execute_transaction(account_id, block_ref, &note_origins, Some(tx_script))
         .unwrap();
+    let account_delta = transaction_result.account_delta().unwrap();
 
     // nonce delta
-    assert!(transaction_result.account_delta().nonce == Some(Felt::new(2)));
+    assert!(account_delta.nonce == Some(Felt::new(2)));
+
+    // code delta
+    assert_eq!(account_delta.code.as_ref(), Some(&new_acct_code_ast));
+    assert_eq!(
+        transaction_result.updated_account_code().map(AccountCode::root),
+        Some(new_acct_code.root())
+    );
 
     // storage delta
-    assert_eq!(transaction_result.account_delta().storage.slots_delta.updated_slots().len(), 1);
+    assert_eq!(account_delta.storage.slots_delta.updated_slots().len(), 1);
     assert_eq!(
-        transaction_result.account_delta().storage.slots_delta.updated_slots()[0].0,
+        account_delta.storage.slots_delta.updated_slots()[0].0,
         CHILD_ROOT_PARENT_LEAF_INDEX as u64
     );
-    assert_eq!(transaction_result.account_delta().storage.store_delta.0.len(), 1);
+    assert_eq!(account_delta.storage.store_delta.0.len(), 1);
     assert_eq!(
-        transaction_result.account_delta().storage.store_delta.0[0].1.cleared_slots()[0],
+        account_delta.storage.store_delta.0[0].1.cleared_slots()[0],
         CHILD_STORAGE_INDEX_0
     );
+
+    // vault delta - all assets of the consumed notes are moved into the created notes
+    assert!(account_delta.vault.is_empty());
+
+    // the new account code is stored in the data store
+    assert_eq!(
+        executor.data_store().account.code(),
+        transaction_result.updated_account_code().unwrap()
+    );
+    assert_eq!(executor.data_store().account.code().root(), new_acct_code.root());
+
+    // the new account interface is loaded into the compiler
+    let new_proc_root = new_acct_code.procedures()[0]
+        .as_bytes()
+        .iter()
+        .fold("0x".to_string(), |hex, byte| format!("{hex}{byte:02x}"));
+    let note_script = ProgramAst::parse(&format!("begin call.{new_proc_root} end")).unwrap();
+    assert!(executor
+        .compile_note_script(note_script, vec![NoteTarget::AccountId(account_id)])
+        .is_ok());
+}
+
+#[test]
+fn test_transaction_result_account_delta_vault() {
+    let assembler = assembler();
+
+    let fungible_asset_1 = mock_fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, 200);
+    let fungible_asset_2 = mock_fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2, 300);
+    let non_fungible_asset_1 = mock_non_fungible_asset(&[1, 2, 3, 4]);
+    let vault =
+        AccountVault::new(&[fungible_asset_1, fungible_asset_2, non_fungible_asset_1]).unwrap();
+    let (account, key_pair) = mock_wallet(vault);
+    let account_id = account.id();
+
+    // the consumed note increases the balance of an asset held by the wallet, and adds a new
+    // fungible and a new non-fungible asset
+    let added_fungible_asset_1 = mock_fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, 100);
+    let added_fungible_asset_3 = mock_fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_3, 50);
+    let added_non_fungible_asset = mock_non_fungible_asset(&[5, 6, 7, 8]);
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let note = create_p2id_note(
+        sender,
+        account_id,
+        &[added_fungible_asset_1, added_fungible_asset_3, added_non_fungible_asset],
+        SERIAL_NUM_1,
+        &assembler,
+    )
+    .unwrap();
+
+    // the transaction script decreases the balance of an asset held by the wallet and removes a
+    // non-fungible asset
+    let removed_fungible_asset_2 = mock_fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2, 120);
+    let tx_script = format!(
+        "\
+        use.context::account_{account_id}
+
+        proc.send_asset
+            call.account_{account_id}::send_asset
+            # => [note_ptr]
+
+            drop
+            # => []
+        end
+
+        begin
+            push.{recipient_1} push.{tag} push.{removed_fungible_asset_2}
+            exec.send_asset
+
+            push.{recipient_2} push.{tag} push.{removed_non_fungible_asset}
+            exec.send_asset
+
+            call.account_{account_id}::auth_tx
+        end
+        ",
+        recipient_1 = prepare_word(&SERIAL_NUM_1),
+        recipient_2 = prepare_word(&SERIAL_NUM_2),
+        tag = Felt::from(NoteTag::from_account_id(sender, NoteExecutionHint::Local).unwrap()),
+        removed_fungible_asset_2 = prepare_word(&removed_fungible_asset_2.into()),
+        removed_non_fungible_asset = prepare_word(&non_fungible_asset_1.into()),
+    );
+
+    let transaction_result =
+        execute_transaction(account, key_pair, vec![note], Some(&tx_script)).unwrap();
+    let vault_delta = transaction_result.account_delta().unwrap().vault.clone();
+
+    // fungible assets are reported by the amount by which their balance changed
+    assert_eq!(vault_delta.added_assets.len(), 3);
+    assert!(vault_delta.added_assets.contains(&added_fungible_asset_1));
+    assert!(vault_delta.added_assets.contains(&added_fungible_asset_3));
+    assert!(vault_delta.added_assets.contains(&added_non_fungible_asset));
+
+    assert_eq!(vault_delta.removed_assets.len(), 2);
+    assert!(vault_delta.removed_assets.contains(&removed_fungible_asset_2));
+    assert!(vault_delta.removed_assets.contains(&non_fungible_asset_1));
+
+    // applying the delta to the initial vault results in the final vault
+    let mut vault =
+        AccountVault::new(&[fungible_asset_1, fungible_asset_2, non_fungible_asset_1]).unwrap();
+    vault.try_apply(vault_delta).unwrap();
+    assert_eq!(vault.get_balance(fungible_asset_1.faucet_id()).unwrap(), 300);
+    assert_eq!(vault.get_balance(fungible_asset_2.faucet_id()).unwrap(), 180);
+    assert_eq!(vault.get_balance(added_fungible_asset_3.faucet_id()).unwrap(), 50);
+    assert!(!vault.has_non_fungible_asset(non_fungible_asset_1).unwrap());
+    assert!(vault.has_non_fungible_asset(added_non_fungible_asset).unwrap());
+}
+
+#[test]
+fn test_transaction_result_account_delta_storage_map() {
+    const MAP_SLOT: u8 = 5;
+    const KEY_0: Word = [Felt::new(1), Felt::new(0), Felt::new(0), Felt::new(0)];
+    const KEY_1: Word = [Felt::new(2), Felt::new(0), Felt::new(0), Felt::new(0)];
+    const KEY_2: Word = [Felt::new(3), Felt::new(0), Felt::new(0), Felt::new(0)];
+    const VALUE_0: Word = [Felt::new(11), Felt::new(12), Felt::new(13), Felt::new(14)];
+    const VALUE_1: Word = [Felt::new(21), Felt::new(22), Felt::new(23), Felt::new(24)];
+    const NEW_VALUE_0: Word = [Felt::new(31), Felt::new(32), Felt::new(33), Felt::new(34)];
+    const VALUE_2: Word = [Felt::new(41), Felt::new(42), Felt::new(43), Felt::new(44)];
+
+    // replace the mock account with an account holding a storage map
+    let mut data_store = MockDataStore::new();
+    let account_id = data_store.account.id();
+    let account_code_ast = ModuleAst::parse(
+        "\
+    use.miden::sat::account
+
+    export.set_map_item
+        exec.account::set_map_item
+        # => [OLD_MAP_ROOT, OLD_VALUE]
+    end
+
+    export.incr_nonce
+        push.0 swap
+        # => [value, 0]
+
+        exec.account::incr_nonce
+        # => [0]
+    end
+    ",
+    )
+    .unwrap();
+    let account_code = AccountCode::new(account_id, account_code_ast, &assembler()).unwrap();
+    let map = StorageMap::with_entries([(KEY_0, VALUE_0), (KEY_1, VALUE_1)]).unwrap();
+    let account_storage =
+        AccountStorage::new(vec![], vec![(MAP_SLOT, map)], MerkleStore::new()).unwrap();
+    data_store.account = Account::new(
+        account_id,
+        data_store.account.vault().clone(),
+        account_storage,
+        account_code,
+        data_store.account.nonce(),
+    );
+    data_store.notes = Vec::new();
+
+    // update the first item, remove the second item and insert a new item
+    let tx_script = format!(
+        "\
+        use.context::account_{account_id}
+
+        begin
+            push.{NEW_VALUE_0} push.{KEY_0} push.{MAP_SLOT}
+            call.account_{account_id}::set_map_item dropw dropw
+
+            padw push.{KEY_1} push.{MAP_SLOT}
+            call.account_{account_id}::set_map_item dropw dropw
+
+            push.{VALUE_2} push.{KEY_2} push.{MAP_SLOT}
+            call.account_{account_id}::set_map_item dropw dropw
+
+            push.1 call.account_{account_id}::incr_nonce drop
+        end
+        ",
+        NEW_VALUE_0 = prepare_word(&NEW_VALUE_0),
+        KEY_0 = prepare_word(&KEY_0),
+        KEY_1 = prepare_word(&KEY_1),
+        VALUE_2 = prepare_word(&VALUE_2),
+        KEY_2 = prepare_word(&KEY_2),
+    );
+    let tx_script = ProgramAst::parse(&tx_script).unwrap();
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num().as_int() as u32;
+    let transaction_result = executor
+        .execute_transaction(account_id, block_ref, &[], Some(tx_script))
+        .unwrap();
+
+    // the delta holds the updated map items
+    let account_delta = transaction_result.account_delta().unwrap();
+    let mut map_items = account_delta.storage.maps_delta[&MAP_SLOT].clone();
+    map_items.sort_by_key(|(key, _)| key[0].as_int());
+    assert_eq!(
+        map_items,
+        vec![(KEY_0, NEW_VALUE_0), (KEY_1, Word::default()), (KEY_2, VALUE_2)]
+    );
+
+    // applying the delta to the initial account results in the final account
+    let mut storage = data_store.account.storage().clone();
+    storage.try_apply(account_delta.storage.clone()).unwrap();
+    assert_eq!(storage.get_map_item(MAP_SLOT, KEY_0), Ok(NEW_VALUE_0));
+    assert_eq!(storage.get_map_item(MAP_SLOT, KEY_1), Ok(Word::default()));
+    assert_eq!(storage.get_map_item(MAP_SLOT, KEY_2), Ok(VALUE_2));
+    assert_eq!(storage.maps()[&MAP_SLOT].root(), storage.get_item(MAP_SLOT));
+
+    let final_account = Account::new(
+        account_id,
+        data_store.account.vault().clone(),
+        storage,
+        data_store.account.code().clone(),
+        account_delta.nonce.unwrap(),
+    );
+    assert_eq!(final_account.hash(), transaction_result.final_account_hash());
+}
+
+#[test]
+fn test_account_code_upgrade() {
+    let data_store = MockDataStore::new();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num().as_int() as u32;
+    let note_origins = data_store
+        .notes
+        .iter()
+        .map(|note| note.proof().as_ref().unwrap().origin().clone())
+        .collect::<Vec<_>>();
+
+    let new_acct_code_ast = ModuleAst::parse(
+        "\
+    export.account_proc_1
+        push.9.9.9.9
+        dropw
+    end
+    ",
+    )
+    .unwrap();
+    let new_acct_code =
+        AccountCode::new(account_id, new_acct_code_ast.clone(), &assembler()).unwrap();
+
+    // the transaction script sets the account code to the new module
+    let tx_script = ProgramAst::parse(&format!(
+        "\
+        use.context::account_{account_id}
+
+        begin
+            push.{NEW_ACCOUNT_ROOT}
+            call.account_{account_id}::set_code
+            dropw
+
+            push.1
+            call.account_{account_id}::incr_nonce
+            drop
+        end
+    ",
+        NEW_ACCOUNT_ROOT = prepare_word(&*new_acct_code.root())
+    ))
+    .unwrap();
+
+    let transaction_result = executor
+        .execute_account_code_upgrade(
+            account_id,
+            block_ref,
+            &note_origins,
+            tx_script,
+            new_acct_code_ast.clone(),
+        )
+        .unwrap();
+
+    // the updated code is included in the transaction result
+    let updated_account_code = transaction_result.updated_account_code().unwrap();
+    assert_eq!(updated_account_code.root(), new_acct_code.root());
+    assert_eq!(
+        transaction_result.account_delta().unwrap().code.as_ref(),
+        Some(&new_acct_code_ast)
+    );
+
+    // the data store holds the updated code
+    assert_eq!(executor.data_store().account.code(), updated_account_code);
+
+    // the new account interface is loaded into the compiler
+    let new_proc_root = new_acct_code.procedures()[0]
+        .as_bytes()
+        .iter()
+        .fold("0x".to_string(), |hex, byte| format!("{hex}{byte:02x}"));
+    let note_script = ProgramAst::parse(&format!("begin call.{new_proc_root} end")).unwrap();
+    assert!(executor
+        .compile_note_script(note_script, vec![NoteTarget::AccountId(account_id)])
+        .is_ok());
+}
+
+#[test]
+fn test_account_code_upgrade_requires_code_update() {
+    let data_store = MockDataStore::new();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num().as_int() as u32;
+    let note_origins = data_store
+        .notes
+        .iter()
+        .map(|note| note.proof().as_ref().unwrap().origin().clone())
+        .collect::<Vec<_>>();
+
+    // the transaction script does not set the account code to the new module
+    let new_acct_code_ast = ModuleAst::parse(
+        "\
+    export.account_proc_1
+        push.9.9.9.9
+        dropw
+    end
+    ",
+    )
+    .unwrap();
+    let tx_script = ProgramAst::parse("begin push.1 drop end").unwrap();
+
+    let result = executor.execute_account_code_upgrade(
+        account_id,
+        block_ref,
+        &note_origins,
+        tx_script,
+        new_acct_code_ast,
+    );
+    assert!(matches!(result, Err(TransactionExecutorError::AccountCodeNotUpgraded(_))));
+}
+
+#[test]
+fn test_transaction_result_new_account() {
+    let data_store = MockDataStore::with_new_account();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num().as_int() as u32;
+    let note_origins = data_store
+        .notes
+        .iter()
+        .map(|note| note.proof().as_ref().unwrap().origin().clone())
+        .collect::<Vec<_>>();
+
+    let transaction_result = executor
+        .execute_transaction(account_id, block_ref, &note_origins, None)
+        .unwrap();
+
+    // the mock new account is private, so only the account hashes are retained
+    assert_eq!(account_id.storage_mode(), Ok(AccountStorageMode::Private));
+    assert_eq!(transaction_result.initial_account_hash(), data_store.account.hash());
+    assert!(transaction_result.new_account().is_none());
+    assert!(transaction_result.account_delta().is_none());
+}
+
+#[test]
+fn test_transaction_executor_authenticator() {
+    let key_pair = KeyPair::new().unwrap();
+    let pub_key: Word = key_pair.public_key().into();
+
+    // replace the mock account with a basic wallet owned by the key pair
+    let mut data_store = MockDataStore::new();
+    data_store.account = mock_basic_wallet(
+        pub_key,
+        data_store.account.vault().clone(),
+        data_store.account.nonce(),
+        &mut assembler(),
+    );
+    let account_id = data_store.account.id();
+    data_store.notes = Vec::new();
+
+    let tx_script = format!(
+        "\
+        use.context::account_{account_id}
+
+        begin
+            call.account_{account_id}::auth_tx
+        end
+        "
+    );
+    let tx_script = ProgramAst::parse(&tx_script).unwrap();
+
+    let mut executor = TransactionExecutor::new(data_store.clone())
+        .with_authenticator(SoftwareKeyAuthenticator::new(key_pair));
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num().as_int() as u32;
+    let transaction_result = executor
+        .execute_transaction(account_id, block_ref, &[], Some(tx_script))
+        .unwrap();
+
+    // the signature was accepted and the nonce was incremented
+    let account_delta = transaction_result.account_delta().unwrap();
+    assert_eq!(account_delta.nonce, Some(Felt::new(2)));
+}
+
+#[test]
+fn test_transaction_executor_authenticator_does_not_sign_script_keys() {
+    let key_pair = KeyPair::new().unwrap();
+    let pub_key: Word = key_pair.public_key().into();
+
+    // replace the mock account with a basic wallet owned by the key pair
+    let mut data_store = MockDataStore::new();
+    data_store.account = mock_basic_wallet(
+        pub_key,
+        data_store.account.vault().clone(),
+        data_store.account.nonce(),
+        &mut assembler(),
+    );
+    let account_id = data_store.account.id();
+    data_store.notes = Vec::new();
+
+    // request a value for a key which is not the message signed by the account before
+    // authenticating the transaction
+    let tx_script = format!(
+        "\
+        use.context::account_{account_id}
+
+        begin
+            push.1.2.3.4 adv.push_mapval dropw
+            call.account_{account_id}::auth_tx
+        end
+        "
+    );
+    let tx_script = ProgramAst::parse(&tx_script).unwrap();
+
+    let signed_messages = Rc::new(RefCell::new(Vec::new()));
+    let authenticator = RecordingAuthenticator {
+        authenticator: SoftwareKeyAuthenticator::new(key_pair),
+        signed_messages: signed_messages.clone(),
+    };
+    let mut executor =
+        TransactionExecutor::new(data_store.clone()).with_authenticator(authenticator);
+    executor.load_account(account_id).unwrap();
+
+    // the key requested by the script is not signed and the transaction fails
+    let block_ref = data_store.block_header.block_num().as_int() as u32;
+    let result = executor.execute_transaction(account_id, block_ref, &[], Some(tx_script));
+    let script_key: Word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    assert!(matches!(
+        result,
+        Err(TransactionExecutorError::ExecuteTransactionProgramFailed(
+            ExecutionError::AdviceKeyNotFound(key)
+        )) if key == script_key
+    ));
+    assert!(signed_messages.borrow().is_empty());
+}
+
+#[test]
+fn test_transaction_executor_p2id_note() {
+    let assembler = assembler();
+
+    // a pay-to-ID note targeting an empty basic wallet
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let asset = mock_fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, 100);
+    let note = create_p2id_note(sender, account_id, &[asset], SERIAL_NUM_1, &assembler).unwrap();
+
+    let transaction_result =
+        execute_wallet_transaction(AccountVault::default(), vec![note]).unwrap();
+
+    // the asset carried by the note was added to the wallet
+    let account_delta = transaction_result.account_delta().unwrap();
+    assert_eq!(account_delta.vault.added_assets, vec![asset]);
+    assert!(account_delta.vault.removed_assets.is_empty());
+}
+
+#[test]
+fn test_transaction_executor_p2idr_note() {
+    let assembler = assembler();
+
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+    let other_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let asset = mock_fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, 100);
+    let future_height = TX_BLOCK_NUM as u32 + 1;
+
+    // the target account can consume the note before the reclaim block height
+    let note =
+        create_p2idr_note(other_id, account_id, &[asset], future_height, SERIAL_NUM_1, &assembler)
+            .unwrap();
+    let transaction_result =
+        execute_wallet_transaction(AccountVault::default(), vec![note]).unwrap();
+    let account_delta = transaction_result.account_delta().unwrap();
+    assert_eq!(account_delta.vault.added_assets, vec![asset]);
+
+    // the sender can reclaim the note once the reclaim block height has been reached
+    let note = create_p2idr_note(
+        account_id,
+        other_id,
+        &[asset],
+        TX_BLOCK_NUM as u32,
+        SERIAL_NUM_1,
+        &assembler,
+    )
+    .unwrap();
+    let transaction_result =
+        execute_wallet_transaction(AccountVault::default(), vec![note]).unwrap();
+    let account_delta = transaction_result.account_delta().unwrap();
+    assert_eq!(account_delta.vault.added_assets, vec![asset]);
+
+    // the sender can not reclaim the note before the reclaim block height
+    let note =
+        create_p2idr_note(account_id, other_id, &[asset], future_height, SERIAL_NUM_1, &assembler)
+            .unwrap();
+    assert!(execute_wallet_transaction(AccountVault::default(), vec![note]).is_err());
+}
+
+#[test]
+fn test_transaction_executor_swap_note() {
+    let assembler = assembler();
+
+    // a swap note offering one asset in exchange for an asset held by the wallet
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let offered_asset = mock_fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, 100);
+    let requested_asset = mock_fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2, 200);
+    let note = create_swap_note(
+        sender,
+        offered_asset,
+        requested_asset,
+        NoteTag::from_account_id(account_id, NoteExecutionHint::Local).unwrap(),
+        SERIAL_NUM_1,
+        SERIAL_NUM_2,
+        &assembler,
+    )
+    .unwrap();
+
+    let vault = AccountVault::new(&[requested_asset]).unwrap();
+    let transaction_result = execute_wallet_transaction(vault, vec![note]).unwrap();
+
+    // the offered asset was added to the wallet and the requested asset was removed from it
+    let account_delta = transaction_result.account_delta().unwrap();
+    assert_eq!(account_delta.vault.added_assets, vec![offered_asset]);
+    assert_eq!(account_delta.vault.removed_assets, vec![requested_asset]);
+
+    // the requested asset was sent to the sender of the swap note in a pay-to-ID payback note
+    let payback_note =
+        create_p2id_note(account_id, sender, &[requested_asset], SERIAL_NUM_2, &assembler).unwrap();
+    let created_notes = transaction_result.created_notes().notes();
+    assert_eq!(created_notes.len(), 1);
+    assert_eq!(created_notes[0].recipient(), &payback_note.recipient());
+    assert_eq!(created_notes[0].vault(), payback_note.vault());
+    assert_eq!(created_notes[0].metadata().sender(), account_id);
+    assert_eq!(created_notes[0].metadata().tag(), payback_note.metadata().tag());
+}
+
+#[test]
+fn test_transaction_executor_basic_fungible_faucet_distribute() {
+    let mut assembler = assembler();
+    let key_pair = KeyPair::new().unwrap();
+    let faucet = mock_basic_fungible_faucet(
+        key_pair.public_key().into(),
+        FAUCET_MAX_SUPPLY,
+        FAUCET_INITIAL_ISSUANCE,
+        &mut assembler,
+    );
+    let faucet_id = faucet.id();
+
+    // the faucet distributes the minted asset to the target account in a pay-to-ID note
+    let target = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+    let expected_note =
+        create_p2id_note(faucet_id, target, &[asset], SERIAL_NUM_1, &assembler).unwrap();
+
+    let tx_script = format!(
+        "\
+        use.context::account_{faucet_id}
+
+        begin
+            push.{recipient}
+            push.{tag}
+            push.100
+            call.account_{faucet_id}::distribute
+            drop
+
+            call.account_{faucet_id}::auth_tx
+        end
+        ",
+        recipient = prepare_word(&expected_note.recipient().into()),
+        tag = Felt::from(expected_note.metadata().tag()),
+    );
+    let transaction_result =
+        execute_transaction(faucet, key_pair, vec![], Some(&tx_script)).unwrap();
+
+    // the total issuance of the faucet was updated and the nonce was incremented
+    let account_delta = transaction_result.account_delta().unwrap();
+    assert_eq!(account_delta.nonce, Some(Felt::new(2)));
+    assert_eq!(account_delta.storage.slots_delta.updated_slots().len(), 1);
+    assert_eq!(
+        account_delta.storage.slots_delta.updated_slots()[0].0,
+        AccountStorage::FAUCET_ISSUANCE_SLOT as u64
+    );
+    assert!(account_delta.vault.is_empty());
+
+    let created_notes = transaction_result.created_notes().notes();
+    assert_eq!(created_notes.len(), 1);
+    assert_eq!(created_notes[0].recipient(), &expected_note.recipient());
+    assert_eq!(created_notes[0].vault(), expected_note.vault());
+    assert_eq!(created_notes[0].metadata().sender(), faucet_id);
+    assert_eq!(created_notes[0].metadata().tag(), expected_note.metadata().tag());
+
+    // the faucet can not distribute more than its max supply
+    let key_pair = KeyPair::new().unwrap();
+    let faucet = mock_basic_fungible_faucet(
+        key_pair.public_key().into(),
+        FAUCET_MAX_SUPPLY,
+        FAUCET_MAX_SUPPLY - 99,
+        &mut assembler,
+    );
+    assert!(execute_transaction(faucet, key_pair, vec![], Some(&tx_script)).is_err());
+}
+
+#[test]
+fn test_transaction_executor_basic_fungible_faucet_burn() {
+    let mut assembler = assembler();
+    let key_pair = KeyPair::new().unwrap();
+    let faucet = mock_basic_fungible_faucet(
+        key_pair.public_key().into(),
+        FAUCET_MAX_SUPPLY,
+        FAUCET_INITIAL_ISSUANCE,
+        &mut assembler,
+    );
+    let faucet_id = faucet.id();
+
+    // a note returning an asset issued by the faucet to be burned
+    let note_script = ProgramAst::parse(
+        "\
+        use.miden::faucets::basic_fungible->faucet
+        use.miden::sat::note
+
+        begin
+            # load the asset of the note onto the stack
+            push.0 exec.note::get_assets drop
+            padw movup.4 mem_loadw
+
+            # burn the asset
+            call.faucet::burn dropw
+        end
+        ",
+    )
+    .unwrap();
+    let (note_script, _) = NoteScript::new(note_script, &assembler).unwrap();
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+    let tag = NoteTag::from_account_id(faucet_id, NoteExecutionHint::Local).unwrap();
+    let note = Note::new(note_script, &[], &[asset], SERIAL_NUM_1, sender, tag, None).unwrap();
+
+    let transaction_result = execute_transaction(faucet, key_pair, vec![note], None).unwrap();
+
+    // the total issuance of the faucet was updated and the nonce was incremented
+    let account_delta = transaction_result.account_delta().unwrap();
+    assert_eq!(account_delta.nonce, Some(Felt::new(2)));
+    assert_eq!(account_delta.storage.slots_delta.updated_slots().len(), 1);
+    assert_eq!(
+        account_delta.storage.slots_delta.updated_slots()[0].0,
+        AccountStorage::FAUCET_ISSUANCE_SLOT as u64
+    );
+    assert!(account_delta.vault.is_empty());
+    assert!(transaction_result.created_notes().notes().is_empty());
 }
 
 #[test]
@@ -307,3 +1006,113 @@ fn test_prove_and_verify_with_tx_executor() {
     let verifier = TransactionVerifier::new(96);
     assert!(verifier.verify(proven_transaction).is_ok());
 }
+
+// HELPERS
+// ================================================================================================
+
+const TX_BLOCK_NUM: u64 = 4;
+const FAUCET_MAX_SUPPLY: u64 = 1_000_000;
+const FAUCET_INITIAL_ISSUANCE: u64 = 1_000;
+const SERIAL_NUM_1: Word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+const SERIAL_NUM_2: Word = [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)];
+
+fn mock_fungible_asset(faucet_id: u64, amount: u64) -> Asset {
+    let faucet_id = AccountId::try_from(faucet_id).unwrap();
+    FungibleAsset::new(faucet_id, amount).unwrap().into()
+}
+
+fn mock_non_fungible_asset(data: &[u8]) -> Asset {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let details = NonFungibleAssetDetails::new(faucet_id, data.to_vec()).unwrap();
+    NonFungibleAsset::new(&details).unwrap().into()
+}
+
+/// A [TransactionAuthenticator] which records the messages it signs.
+struct RecordingAuthenticator {
+    authenticator: SoftwareKeyAuthenticator,
+    signed_messages: Rc<RefCell<Vec<Word>>>,
+}
+
+impl TransactionAuthenticator for RecordingAuthenticator {
+    fn get_signature(
+        &self,
+        account_id: AccountId,
+        message: Word,
+    ) -> Result<Vec<Felt>, AuthenticatorError> {
+        self.signed_messages.borrow_mut().push(message);
+        self.authenticator.get_signature(account_id, message)
+    }
+}
+
+/// Returns a basic wallet holding the specified vault together with the key pair of the wallet
+/// owner. The wallet has the nonce of the mock account.
+fn mock_wallet(vault: AccountVault) -> (Account, KeyPair) {
+    let key_pair = KeyPair::new().unwrap();
+    let pub_key: Word = key_pair.public_key().into();
+
+    let nonce = MockDataStore::new().account.nonce();
+    let account = mock_basic_wallet(pub_key, vault, nonce, &mut assembler());
+
+    (account, key_pair)
+}
+
+/// Executes a transaction which consumes the specified notes against a basic wallet holding the
+/// specified vault. The mock account is replaced with the wallet, and the transaction is
+/// authenticated with the key of the wallet owner.
+fn execute_wallet_transaction(
+    vault: AccountVault,
+    notes: Vec<Note>,
+) -> Result<TransactionResult, TransactionExecutorError> {
+    let (account, key_pair) = mock_wallet(vault);
+    let account_id = account.id();
+
+    let tx_script = format!(
+        "\
+        use.context::account_{account_id}
+
+        begin
+            call.account_{account_id}::auth_tx
+        end
+        "
+    );
+
+    execute_transaction(account, key_pair, notes, Some(&tx_script))
+}
+
+/// Executes a transaction which consumes the specified notes against the specified account and
+/// runs the specified transaction script. The transaction is executed against the block
+/// [TX_BLOCK_NUM], and signatures requested by the transaction are produced with the specified
+/// key pair.
+fn execute_transaction(
+    account: Account,
+    key_pair: KeyPair,
+    mut notes: Vec<Note>,
+    tx_script: Option<&str>,
+) -> Result<TransactionResult, TransactionExecutorError> {
+    let mut data_store = MockDataStore::new();
+    data_store.account = account;
+    data_store.block_chain = mock_chain_data(&mut notes);
+    data_store.block_header = mock_block_header(
+        Felt::new(TX_BLOCK_NUM),
+        Some(data_store.block_chain.mmr().accumulator().hash_peaks().into()),
+        None,
+        &[data_store.account.clone()],
+    );
+    data_store.notes = notes;
+
+    let account_id = data_store.account.id();
+    let tx_script = tx_script.map(|tx_script| ProgramAst::parse(tx_script).unwrap());
+
+    let mut executor = TransactionExecutor::new(data_store.clone())
+        .with_authenticator(SoftwareKeyAuthenticator::new(key_pair));
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num().as_int() as u32;
+    let note_origins = data_store
+        .notes
+        .iter()
+        .map(|note| note.proof().as_ref().unwrap().origin().clone())
+        .collect::<Vec<_>>();
+
+    executor.execute_transaction(account_id, block_ref, &note_origins, tx_script)
+}
@@ -1,6 +1,8 @@
 use criterion::{criterion_group, criterion_main, Criterion};
 use crypto::hash::rpo::RpoDigest as Digest;
-use miden_objects::{AccountId, AccountType};
+use miden_objects::{
+    AccountId, AccountSeedGrinder, AccountStorageMode, AccountType, CancellationToken,
+};
 
 fn grind_account_seed(c: &mut Criterion) {
     let init_seed = [
@@ -13,7 +15,7 @@ fn grind_account_seed(c: &mut Criterion) {
             AccountId::get_account_seed(
                 init_seed,
                 AccountType::RegularAccountImmutableCode,
-                true,
+                AccountStorageMode::Public,
                 Digest::default(),
                 Digest::default(),
             )
@@ -25,12 +27,25 @@ fn grind_account_seed(c: &mut Criterion) {
             AccountId::get_account_seed(
                 init_seed,
                 AccountType::FungibleFaucet,
-                true,
+                AccountStorageMode::Public,
                 Digest::default(),
                 Digest::default(),
             )
         })
     });
+
+    c.bench_function("Grind fungible faucet on-chain account seed in parallel", |bench| {
+        bench.iter(|| {
+            AccountSeedGrinder::new(
+                init_seed,
+                AccountType::FungibleFaucet,
+                AccountStorageMode::Public,
+                Digest::default(),
+                Digest::default(),
+            )
+            .grind(&CancellationToken::new(), |_| {})
+        })
+    });
 }
 
 criterion_group!(account_seed, grind_account_seed);
@@ -1,12 +1,12 @@
-use super::{Account, AccountError, Digest, Felt, Hasher, StarkField, ToString, Vec, Word};
-use core::{fmt, ops::Deref};
+use super::{Account, AccountError, Digest, Felt, Hasher, StarkField, String, ToString, Vec, Word};
+use core::{fmt, ops::Deref, str::FromStr};
 use crypto::FieldElement;
 
 // ACCOUNT ID
 // ================================================================================================
 
 /// Specifies the account type.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AccountType {
     FungibleFaucet,
     NonFungibleFaucet,
@@ -14,6 +14,44 @@ pub enum AccountType {
     RegularAccountUpdatableCode,
 }
 
+/// Specifies how the account data is stored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountStorageMode {
+    /// The full account state is stored on-chain.
+    Public,
+    /// The account state is stored on-chain in encrypted form.
+    Encrypted,
+    /// Only the account hash is stored on-chain which serves as a commitment to the account state.
+    Private,
+}
+
+/// Specifies the network for which an account ID is encoded in its textual representation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetworkId {
+    Mainnet,
+    Testnet,
+    Devnet,
+}
+
+impl NetworkId {
+    /// Returns the human-readable prefix of account IDs encoded for this network.
+    pub const fn hrp(&self) -> &'static str {
+        match self {
+            Self::Mainnet => "mm",
+            Self::Testnet => "mtst",
+            Self::Devnet => "mdev",
+        }
+    }
+
+    /// Returns the network with the specified human-readable prefix, or None if there is no such
+    /// network.
+    pub fn from_hrp(hrp: &str) -> Option<Self> {
+        [Self::Mainnet, Self::Testnet, Self::Devnet]
+            .into_iter()
+            .find(|network| network.hrp() == hrp)
+    }
+}
+
 /// Unique identifier of an account.
 ///
 /// Account ID consists of 1 field element (~64 bits). This field element uniquely identifies a
@@ -23,10 +61,13 @@ pub enum AccountType {
 ///  - 01 - regular account with immutable code.
 ///  - 10 - fungible asset faucet with immutable code.
 ///  - 11 - non-fungible asset faucet with immutable code.
-/// - The third most significant bit of the ID specifies whether the account data is stored on-chain:
-///  - 0 - full account data is stored on-chain.
-///  - 1 - only the account hash is stored on-chain which serves as a commitment to the account state.
-/// As such the three most significant bits fully describes the type of the account.
+/// - The third and fourth most significant bits of the ID specify the storage mode of the account:
+///  - 00 - public, full account data is stored on-chain.
+///  - 01 - encrypted, account data is stored on-chain in encrypted form.
+///  - 11 - private, only the account hash is stored on-chain which serves as a commitment to the
+///    account state.
+///  - 10 - invalid.
+/// As such the four most significant bits fully describe the type of the account.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "serde", serde(transparent))]
@@ -39,7 +80,9 @@ impl AccountId {
     pub const NON_FUNGIBLE_FAUCET_TAG: u64 = 0b11;
     pub const REGULAR_ACCOUNT_UPDATABLE_CODE_TAG: u64 = 0b00;
     pub const REGULAR_ACCOUNT_IMMUTABLE_CODE_TAG: u64 = 0b01;
-    pub const ON_CHAIN_ACCOUNT_SELECTOR: u64 = 0b001;
+    pub const PUBLIC_STORAGE_MODE_TAG: u64 = 0b00;
+    pub const ENCRYPTED_STORAGE_MODE_TAG: u64 = 0b01;
+    pub const PRIVATE_STORAGE_MODE_TAG: u64 = 0b11;
 
     /// Specifies a minimum number of trailing zeros required in the last element of the seed digest.
     pub const REGULAR_ACCOUNT_SEED_DIGEST_MIN_TRAILING_ZEROS: u32 = 23;
@@ -63,6 +106,7 @@ impl AccountId {
     /// # Errors
     /// Returns an error if the resulting account ID does not comply with account ID rules:
     /// - the ID has at least `5` ones.
+    /// - the ID specifies a valid storage mode.
     /// - the ID has at least `23` trailing zeros if it is a regular account.
     /// - the ID has at least `31` trailing zeros if it is a faucet account.
     pub fn new(seed: Word, code_root: Digest, storage_root: Digest) -> Result<Self, AccountError> {
@@ -112,20 +156,72 @@ impl AccountId {
         )
     }
 
+    /// Returns the storage mode of this account ID.
+    ///
+    /// # Errors
+    /// Returns an error if the storage mode bits of the ID are `10`. This is only possible for IDs
+    /// which were not validated on construction (e.g., IDs of deserialized accounts).
+    pub fn storage_mode(&self) -> Result<AccountStorageMode, AccountError> {
+        Self::parse_storage_mode(self.0)
+    }
+
     /// Returns true if an account with this ID is an on-chain account.
+    ///
+    /// Both public and encrypted accounts are stored on-chain.
     pub fn is_on_chain(&self) -> bool {
-        self.0.as_int() >> 61 & Self::ON_CHAIN_ACCOUNT_SELECTOR == 1
+        matches!(
+            self.storage_mode(),
+            Ok(AccountStorageMode::Public | AccountStorageMode::Encrypted)
+        )
+    }
+
+    // BECH32 ENCODING
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the bech32 encoding of this account ID for the specified network.
+    ///
+    /// The encoding consists of the human-readable prefix of the network, the separator `1`, the
+    /// little-endian bytes of the ID and a six character bech32m checksum as specified in BIP-350.
+    pub fn to_bech32(&self, network: NetworkId) -> String {
+        bech32_encode(network.hrp(), &<[u8; 8]>::from(*self))
+    }
+
+    /// Parses an account ID from its bech32 encoding for the specified network.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The encoding is malformed.
+    /// - The checksum of the encoding is invalid.
+    /// - The encoding is for a network other than the specified network.
+    /// - The encoded bytes are not a valid account ID.
+    pub fn from_bech32(encoded: &str, network: NetworkId) -> Result<Self, AccountError> {
+        let (hrp, data) = bech32_decode(encoded)?;
+        if hrp != network.hrp() {
+            return Err(AccountError::AccountIdBech32UnexpectedNetwork(hrp));
+        }
+        Self::from_bech32_data(&data)
+    }
+
+    /// Returns the account ID encoded by the provided bech32 data.
+    fn from_bech32_data(data: &[u8]) -> Result<Self, AccountError> {
+        let bytes: [u8; 8] = data.try_into().map_err(|_| {
+            AccountError::AccountIdBech32Malformed(format!(
+                "expected 8 bytes of data, but found {}",
+                data.len()
+            ))
+        })?;
+        Self::try_from(bytes)
     }
 
     // SEED GENERATORS
     // --------------------------------------------------------------------------------------------
 
     /// Finds and returns a seed suitable for creating an account ID for the specified account type
-    /// using the provided initial seed as a starting point.
+    /// and storage mode using the provided initial seed as a starting point.
     pub fn get_account_seed(
         init_seed: [u8; 32],
         account_type: AccountType,
-        on_chain: bool,
+        storage_mode: AccountStorageMode,
         code_root: Digest,
         storage_root: Digest,
     ) -> Result<Word, AccountError> {
@@ -142,14 +238,8 @@ impl AccountId {
         // loop until we have a seed that satisfies the specified account type.
         loop {
             // check if the seed satisfies the specified account type
-            if AccountId::validate_seed_digest(&current_digest).is_ok() {
-                if let Ok(account_id) = AccountId::try_from(current_digest[0]) {
-                    if account_id.account_type() == account_type
-                        && account_id.is_on_chain() == on_chain
-                    {
-                        return Ok(current_seed);
-                    };
-                }
+            if Self::seed_digest_matches(&current_digest, account_type, storage_mode) {
+                return Ok(current_seed);
             }
             current_seed = current_digest.into();
             current_digest = Self::compute_digest(current_seed, code_root, storage_root);
@@ -161,7 +251,7 @@ impl AccountId {
 
     /// Returns the digest of two hashing permutations over the seed, code root, storage root and
     /// padding.
-    fn compute_digest(seed: Word, code_root: Digest, storage_root: Digest) -> Digest {
+    pub(super) fn compute_digest(seed: Word, code_root: Digest, storage_root: Digest) -> Digest {
         let mut elements = Vec::with_capacity(16);
         elements.extend(seed);
         elements.extend(*code_root);
@@ -170,11 +260,31 @@ impl AccountId {
         Hasher::hash_elements(&elements)
     }
 
+    /// Returns true if the provided seed digest is valid and derives an account ID of the specified
+    /// account type and storage mode.
+    pub(super) fn seed_digest_matches(
+        digest: &Digest,
+        account_type: AccountType,
+        storage_mode: AccountStorageMode,
+    ) -> bool {
+        if Self::validate_seed_digest(digest).is_err() {
+            return false;
+        }
+        match AccountId::try_from(digest[0]) {
+            Ok(account_id) => {
+                account_id.account_type() == account_type
+                    && account_id.storage_mode() == Ok(storage_mode)
+            }
+            Err(_) => false,
+        }
+    }
+
     /// Returns an error if:
     /// - There are fewer then:
     ///     - 24 trailing ZEROs in the last element of the seed digest for regular accounts.
     ///     - 32 trailing ZEROs in the last element of the seed digest for faucet accounts.
     /// - There are fewer than 5 ONEs in the account ID (first element of the seed digest).
+    /// - The account ID (first element of the seed digest) does not specify a valid storage mode.
     pub fn validate_seed_digest(digest: &Digest) -> Result<(), AccountError> {
         let elements = digest.as_elements();
 
@@ -183,6 +293,9 @@ impl AccountId {
             return Err(AccountError::account_id_too_few_ones());
         }
 
+        // accounts must specify a valid storage mode.
+        Self::parse_storage_mode(elements[0])?;
+
         // we require that accounts have at least some number of trailing zeros in the last element,
         let is_regular_account = elements[0].as_int() >> 63 == 0;
         let pow_trailing_zeros = elements[3].as_int().trailing_zeros();
@@ -203,13 +316,26 @@ impl AccountId {
 
     /// Returns an error if:
     /// - There are fewer then 5 ONEs in the account ID.
+    /// - The account ID does not specify a valid storage mode.
     fn validate(&self) -> Result<(), AccountError> {
         if self.0.as_int().count_ones() < Self::MIN_ACCOUNT_ONES {
             return Err(AccountError::account_id_too_few_ones());
         }
+        Self::parse_storage_mode(self.0)?;
 
         Ok(())
     }
+
+    /// Returns the storage mode specified by the third and fourth most significant bits of the
+    /// provided account ID, or an error if these bits are `10`.
+    fn parse_storage_mode(id: Felt) -> Result<AccountStorageMode, AccountError> {
+        match id.as_int() >> 60 & 0b11 {
+            Self::PUBLIC_STORAGE_MODE_TAG => Ok(AccountStorageMode::Public),
+            Self::ENCRYPTED_STORAGE_MODE_TAG => Ok(AccountStorageMode::Encrypted),
+            Self::PRIVATE_STORAGE_MODE_TAG => Ok(AccountStorageMode::Private),
+            _ => Err(AccountError::AccountIdInvalidStorageMode),
+        }
+    }
 }
 
 impl Deref for AccountId {
@@ -274,6 +400,19 @@ impl fmt::Display for AccountId {
     }
 }
 
+impl FromStr for AccountId {
+    type Err = AccountError;
+
+    /// Parses an account ID from its bech32 encoding for any of the known networks.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (hrp, data) = bech32_decode(s)?;
+        if NetworkId::from_hrp(&hrp).is_none() {
+            return Err(AccountError::AccountIdBech32UnexpectedNetwork(hrp));
+        }
+        Self::from_bech32_data(&data)
+    }
+}
+
 impl PartialOrd for AccountId {
     fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         Some(self.cmp(other))
@@ -293,6 +432,144 @@ fn parse_felt(bytes: &[u8]) -> Result<Felt, AccountError> {
     Felt::try_from(bytes).map_err(|err| AccountError::AccountIdInvalidFieldElement(err.to_string()))
 }
 
+// BECH32 HELPERS
+// ================================================================================================
+
+/// The characters used to encode 5-bit values in bech32.
+const BECH32_CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// The constant the checksum is combined with in the bech32m variant of bech32.
+const BECH32M_CONST: u32 = 0x2bc830a3;
+
+/// The number of characters of the bech32 checksum.
+const BECH32_CHECKSUM_LENGTH: usize = 6;
+
+/// The maximum number of characters of a bech32 string.
+const BECH32_MAX_LENGTH: usize = 90;
+
+/// Returns the bech32m encoding of the provided data with the provided human-readable prefix.
+pub(super) fn bech32_encode(hrp: &str, data: &[u8]) -> String {
+    let data = convert_bits(data, 8, 5, true).expect("padding is allowed");
+
+    let mut values = bech32_hrp_expand(hrp);
+    values.extend(&data);
+    values.extend([0; BECH32_CHECKSUM_LENGTH]);
+    let polymod = bech32_polymod(&values) ^ BECH32M_CONST;
+    let checksum = (0..BECH32_CHECKSUM_LENGTH).map(|i| ((polymod >> (5 * (5 - i))) & 31) as u8);
+
+    let mut encoded = String::from(hrp);
+    encoded.push('1');
+    encoded.extend(data.into_iter().chain(checksum).map(|v| BECH32_CHARSET[v as usize] as char));
+    encoded
+}
+
+/// Decodes the provided bech32m string into its human-readable prefix and data.
+fn bech32_decode(encoded: &str) -> Result<(String, Vec<u8>), AccountError> {
+    let (hrp, values) = bech32_decode_values(encoded)?;
+    let data = convert_bits(&values, 5, 8, false)
+        .ok_or_else(|| AccountError::AccountIdBech32Malformed("invalid padding".to_string()))?;
+
+    Ok((hrp, data))
+}
+
+/// Decodes the provided bech32m string into its human-readable prefix and the 5-bit values of its
+/// data, excluding the checksum.
+pub(super) fn bech32_decode_values(encoded: &str) -> Result<(String, Vec<u8>), AccountError> {
+    let malformed = |msg: &str| AccountError::AccountIdBech32Malformed(msg.to_string());
+
+    if encoded.len() > BECH32_MAX_LENGTH {
+        return Err(malformed("too long"));
+    }
+
+    let has_lowercase = encoded.bytes().any(|b| b.is_ascii_lowercase());
+    let has_uppercase = encoded.bytes().any(|b| b.is_ascii_uppercase());
+    if has_lowercase && has_uppercase {
+        return Err(malformed("mixed case"));
+    }
+    let encoded = encoded.to_ascii_lowercase();
+
+    let (hrp, data) = encoded.rsplit_once('1').ok_or_else(|| malformed("missing separator"))?;
+    if hrp.is_empty() || hrp.bytes().any(|b| !(33..=126).contains(&b)) {
+        return Err(malformed("invalid human-readable prefix"));
+    }
+    if data.len() < BECH32_CHECKSUM_LENGTH {
+        return Err(malformed("missing checksum"));
+    }
+
+    let mut values = data
+        .bytes()
+        .map(|c| BECH32_CHARSET.iter().position(|x| *x == c).map(|v| v as u8))
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(|| malformed("invalid character"))?;
+
+    let mut checked_values = bech32_hrp_expand(hrp);
+    checked_values.extend(&values);
+    if bech32_polymod(&checked_values) != BECH32M_CONST {
+        return Err(AccountError::AccountIdBech32InvalidChecksum);
+    }
+
+    values.truncate(values.len() - BECH32_CHECKSUM_LENGTH);
+
+    Ok((hrp.to_string(), values))
+}
+
+/// Computes the bech32 checksum polynomial over the provided 5-bit values.
+fn bech32_polymod(values: &[u8]) -> u32 {
+    const GENERATORS: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+
+    let mut checksum = 1_u32;
+    for value in values {
+        let top = checksum >> 25;
+        checksum = ((checksum & 0x1ffffff) << 5) ^ *value as u32;
+        for (i, generator) in GENERATORS.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                checksum ^= generator;
+            }
+        }
+    }
+    checksum
+}
+
+/// Expands the human-readable prefix into the values used for computing the checksum.
+fn bech32_hrp_expand(hrp: &str) -> Vec<u8> {
+    hrp.bytes()
+        .map(|b| b >> 5)
+        .chain([0])
+        .chain(hrp.bytes().map(|b| b & 31))
+        .collect()
+}
+
+/// Regroups the bits of the provided values from groups of `from` bits into groups of `to` bits.
+///
+/// If `pad` is true, the last group is padded with zeros. Otherwise, None is returned if the
+/// values contain incomplete groups with non-zero bits.
+fn convert_bits(data: &[u8], from: u32, to: u32, pad: bool) -> Option<Vec<u8>> {
+    let max_value = (1_u32 << to) - 1;
+    let max_acc = (1_u32 << (from + to - 1)) - 1;
+
+    let mut acc = 0_u32;
+    let mut bits = 0_u32;
+    let mut result = Vec::new();
+    for value in data {
+        acc = ((acc << from) | *value as u32) & max_acc;
+        bits += from;
+        while bits >= to {
+            bits -= to;
+            result.push(((acc >> bits) & max_value) as u8);
+        }
+    }
+
+    if pad {
+        if bits > 0 {
+            result.push(((acc << (to - bits)) & max_value) as u8);
+        }
+    } else if bits >= from || ((acc << (to - bits)) & max_value) != 0 {
+        return None;
+    }
+
+    Some(result)
+}
+
 // HELPERS
 // --------------------------------------------------------------------------------------------
 /// Validates that the provided seed is valid for the provided account.
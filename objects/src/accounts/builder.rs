@@ -0,0 +1,269 @@
+use super::{
+    Account, AccountCode, AccountError, AccountId, AccountStorage, AccountStorageMode, AccountType,
+    AccountVault, Assembler, BTreeMap, Digest, LibraryPath, ModuleAst, ProcedureMetadata,
+    StorageItem, StorageMap, String, ToString, Vec, Word, ZERO,
+};
+#[cfg(feature = "std")]
+use super::{AccountSeedGrinder, CancellationToken, SeedGrindingOutcome};
+use assembly::ast::{CodeBody, Instruction, Node, ProcedureId};
+use crypto::merkle::MerkleStore;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Path of the module exposing the account procedures of the transaction kernel to accounts.
+const SAT_ACCOUNT_MODULE_PATH: &str = "miden::sat::account";
+
+/// Name of the procedure which updates the code of an account.
+const SET_CODE_PROCEDURE: &str = "set_code";
+
+/// Name of the kernel procedure which updates the code of an account.
+const SET_CODE_KERNEL_PROCEDURE: &str = "set_account_code";
+
+// ACCOUNT BUILDER
+// ================================================================================================
+
+/// A builder of new accounts.
+///
+/// The builder compiles the account code, builds the account storage, and grinds a seed for the
+/// account ID of the specified type and storage mode, unless a precomputed seed is provided via
+/// [AccountBuilder::account_seed()]. The resulting account has a nonce of zero
+/// and an empty vault, as required by the transaction kernel for new accounts. The seed returned
+/// together with the account must be provided to the first transaction executed against the
+/// account.
+#[derive(Debug, Clone)]
+pub struct AccountBuilder {
+    init_seed: [u8; 32],
+    account_seed: Option<Word>,
+    account_type: AccountType,
+    storage_mode: AccountStorageMode,
+    code: ModuleAst,
+    procedure_metadata: BTreeMap<String, ProcedureMetadata>,
+    storage_items: Vec<StorageItem>,
+    storage_maps: Vec<(u8, StorageMap)>,
+    merkle_store: MerkleStore,
+}
+
+impl AccountBuilder {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new builder of an account with the code defined by the provided module. The
+    /// account seed is ground starting from the provided initial seed.
+    ///
+    /// By default, the builder creates a public regular account with updatable code and empty
+    /// storage.
+    pub fn new(init_seed: [u8; 32], code: ModuleAst) -> Self {
+        Self {
+            init_seed,
+            account_seed: None,
+            account_type: AccountType::RegularAccountUpdatableCode,
+            storage_mode: AccountStorageMode::Public,
+            code,
+            procedure_metadata: BTreeMap::new(),
+            storage_items: Vec::new(),
+            storage_maps: Vec::new(),
+            merkle_store: MerkleStore::new(),
+        }
+    }
+
+    // MODIFIERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Sets the type of the account.
+    pub fn account_type(mut self, account_type: AccountType) -> Self {
+        self.account_type = account_type;
+        self
+    }
+
+    /// Sets the storage mode of the account.
+    pub fn storage_mode(mut self, storage_mode: AccountStorageMode) -> Self {
+        self.storage_mode = storage_mode;
+        self
+    }
+
+    /// Attaches the provided metadata to the exported procedure with the specified name.
+    pub fn procedure_metadata(mut self, name: &str, metadata: ProcedureMetadata) -> Self {
+        self.procedure_metadata.insert(name.to_string(), metadata);
+        self
+    }
+
+    /// Adds an item to the storage of the account.
+    pub fn storage_item(mut self, item: StorageItem) -> Self {
+        self.storage_items.push(item);
+        self
+    }
+
+    /// Adds a map at the specified slot index to the storage of the account.
+    pub fn storage_map(mut self, index: u8, map: StorageMap) -> Self {
+        self.storage_maps.push((index, map));
+        self
+    }
+
+    /// Sets the Merkle store which backs the array slots of the account storage.
+    pub fn merkle_store(mut self, merkle_store: MerkleStore) -> Self {
+        self.merkle_store = merkle_store;
+        self
+    }
+
+    /// Sets a precomputed seed from which the account ID is derived instead of grinding one.
+    ///
+    /// The seed must have been ground for the code, the storage, the type and the storage mode of
+    /// the account, e.g. via [AccountSeedGrinder](super::AccountSeedGrinder).
+    pub fn account_seed(mut self, seed: Word) -> Self {
+        self.account_seed = Some(seed);
+        self
+    }
+
+    // BUILDER
+    // --------------------------------------------------------------------------------------------
+    /// Builds the account and returns it together with the seed from which its ID was derived.
+    ///
+    /// The provided assembler must be instantiated with the Miden library, the standard library
+    /// and the transaction kernel.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The account storage could not be built.
+    /// - The account type does not support code updates but a procedure of the account code
+    ///   directly invokes `miden::sat::account::set_code` or the `set_account_code` kernel
+    ///   procedure. This is a best-effort check only, see [updates_code()].
+    /// - The account code could not be compiled, or procedure metadata was provided for a
+    ///   procedure which is not exported by the account code.
+    /// - The precomputed account seed does not derive a valid account ID of the specified type and
+    ///   storage mode.
+    pub fn build(self, assembler: &Assembler) -> Result<(Account, Word), AccountError> {
+        let (init_seed, account_type, storage_mode, account_seed) =
+            (self.init_seed, self.account_type, self.storage_mode, self.account_seed);
+        let (code, storage) = self.build_code_and_storage(assembler)?;
+
+        let seed = match account_seed {
+            Some(seed) => seed,
+            None => grind_account_seed(
+                init_seed,
+                account_type,
+                storage_mode,
+                code.root(),
+                storage.root(),
+            )?,
+        };
+        let id = AccountId::new(seed, code.root(), storage.root())?;
+        if id.account_type() != account_type || id.storage_mode() != Ok(storage_mode) {
+            return Err(AccountError::AccountIdSeedMismatch(seed));
+        }
+
+        let account = Account::new(id, AccountVault::default(), storage, code, ZERO);
+
+        Ok((account, seed))
+    }
+
+    /// Builds the account with the specified ID without grinding a seed for it.
+    ///
+    /// This allows testing account constructors without computing a valid account seed.
+    #[cfg(test)]
+    pub(crate) fn build_with_id(
+        self,
+        id: AccountId,
+        assembler: &Assembler,
+    ) -> Result<Account, AccountError> {
+        let (code, storage) = self.build_code_and_storage(assembler)?;
+        Ok(Account::new(id, AccountVault::default(), storage, code, ZERO))
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Builds the account storage and compiles the account code.
+    fn build_code_and_storage(
+        self,
+        assembler: &Assembler,
+    ) -> Result<(AccountCode, AccountStorage), AccountError> {
+        let storage =
+            AccountStorage::new(self.storage_items, self.storage_maps, self.merkle_store)?;
+
+        if self.account_type != AccountType::RegularAccountUpdatableCode && updates_code(&self.code)
+        {
+            return Err(AccountError::AccountCodeUpdatesNotSupported(self.account_type));
+        }
+
+        // the procedure roots do not depend on the account ID, so the code compiled against a
+        // placeholder ID is the code of the account
+        let code = AccountCode::with_metadata(
+            AccountId::new_unchecked(ZERO),
+            self.code,
+            &self.procedure_metadata,
+            assembler,
+        )?;
+
+        Ok((code, storage))
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Grinds a seed for an account ID of the specified type and storage mode using all CPUs available
+/// to the process.
+#[cfg(feature = "std")]
+fn grind_account_seed(
+    init_seed: [u8; 32],
+    account_type: AccountType,
+    storage_mode: AccountStorageMode,
+    code_root: Digest,
+    storage_root: Digest,
+) -> Result<Word, AccountError> {
+    let mut grinder =
+        AccountSeedGrinder::new(init_seed, account_type, storage_mode, code_root, storage_root);
+    match grinder.grind(&CancellationToken::new(), |_| ()) {
+        SeedGrindingOutcome::Found(seed) => Ok(seed),
+        SeedGrindingOutcome::Cancelled => unreachable!("grinding is never cancelled"),
+    }
+}
+
+/// Grinds a seed for an account ID of the specified type and storage mode on the current thread.
+#[cfg(not(feature = "std"))]
+fn grind_account_seed(
+    init_seed: [u8; 32],
+    account_type: AccountType,
+    storage_mode: AccountStorageMode,
+    code_root: Digest,
+    storage_root: Digest,
+) -> Result<Word, AccountError> {
+    AccountId::get_account_seed(init_seed, account_type, storage_mode, code_root, storage_root)
+}
+
+/// Returns true if any procedure of the provided module updates the code of the account.
+///
+/// Only direct invocations of `miden::sat::account::set_code` and of the `set_account_code` kernel
+/// procedure are detected. Code updates performed through other library procedures which invoke
+/// these procedures are not detected. The check thus only catches obvious mistakes early, while
+/// the actual enforcement is performed by the transaction kernel, which rejects code updates of
+/// accounts whose type does not support them.
+fn updates_code(module: &ModuleAst) -> bool {
+    let set_code = ProcedureId::from_name(
+        SET_CODE_PROCEDURE,
+        &LibraryPath::new(SAT_ACCOUNT_MODULE_PATH).expect("valid path"),
+    );
+    let set_account_code = ProcedureId::from_kernel_name(SET_CODE_KERNEL_PROCEDURE);
+
+    module
+        .procs()
+        .iter()
+        .any(|proc| invokes_any(&proc.body, &[set_code, set_account_code]))
+}
+
+/// Returns true if the provided code body invokes any of the specified procedures.
+fn invokes_any(body: &CodeBody, targets: &[ProcedureId]) -> bool {
+    body.nodes().iter().any(|node| match node {
+        Node::Instruction(
+            Instruction::ExecImported(id)
+            | Instruction::CallImported(id)
+            | Instruction::SysCall(id),
+        ) => targets.contains(id),
+        Node::Instruction(_) => false,
+        Node::IfElse {
+            true_case,
+            false_case,
+        } => invokes_any(true_case, targets) || invokes_any(false_case, targets),
+        Node::Repeat { body, .. } | Node::While { body } => invokes_any(body, targets),
+    })
+}
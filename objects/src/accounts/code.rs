@@ -1,6 +1,6 @@
 use super::{
-    AccountError, AccountId, Assembler, AssemblyContext, AssemblyContextType, Digest, LibraryPath,
-    Module, ModuleAst, Vec,
+    AccountError, AccountId, Assembler, AssemblyContext, AssemblyContextType, BTreeMap, Digest,
+    Felt, Hasher, LibraryPath, Module, ModuleAst, String, ToString, Vec, Word, ZERO,
 };
 use crypto::merkle::SimpleSmt;
 
@@ -11,6 +11,11 @@ use crypto::merkle::SimpleSmt;
 // ------------------------------------------------------------------------------------------------
 
 /// The depth of the Merkle tree that is used to commit to the account's public interface.
+///
+/// The depth must match the `ACCOUNT_CODE_TREE_DEPTH` constant of the transaction kernel. Changing
+/// the depth changes the commitments to all account interfaces, and thus invalidates the seeds of
+/// all existing account IDs. The plan for raising this limit is described in the "Larger account
+/// interfaces" section of the accounts documentation.
 const ACCOUNT_CODE_TREE_DEPTH: u8 = 8;
 
 /// Describes the public interface of an account.
@@ -18,12 +23,21 @@ const ACCOUNT_CODE_TREE_DEPTH: u8 = 8;
 /// Account's public interface consists of a set of account procedures, each procedure being a Miden
 /// VM program. Thus, MAST root of each procedure commits to the underlying program. We commit to
 /// the entire account interface by building a simple Merkle tree out of all procedure MAST roots.
+/// The tree has one leaf per procedure, and thus an account can expose at most
+/// [AccountCode::MAX_NUM_PROCEDURES] procedures.
+///
+/// Each procedure may also carry [ProcedureMetadata] restricting how the procedure can be invoked.
+/// The leaf of a procedure with non-empty metadata commits to both the procedure MAST root and the
+/// metadata as hash(root, metadata). The leaf of a procedure with empty metadata is the procedure
+/// MAST root itself.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct AccountCode {
     #[cfg_attr(feature = "serde", serde(with = "serialization"))]
     module: ModuleAst,
     procedures: Vec<Digest>,
+    procedure_metadata: Vec<ProcedureMetadata>,
+    procedure_names: BTreeMap<String, Digest>,
     procedure_tree: SimpleSmt,
 }
 
@@ -57,6 +71,9 @@ impl AccountCode {
     // --------------------------------------------------------------------------------------------
     pub const ACCOUNT_CODE_NAMESPACE_BASE: &'static str = "context::account";
 
+    /// The maximum number of procedures an account can expose.
+    pub const MAX_NUM_PROCEDURES: usize = 1 << ACCOUNT_CODE_TREE_DEPTH;
+
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
     /// Creates and returns a new definition of an account's interface compiled from the specified
@@ -66,35 +83,94 @@ impl AccountCode {
         account_module: ModuleAst,
         assembler: &Assembler,
     ) -> Result<Self, AccountError> {
-        let module = Module::new(
-            LibraryPath::new(format!("{}_{}", Self::ACCOUNT_CODE_NAMESPACE_BASE, account_id))
-                .expect("valid path"),
-            account_module,
-        );
+        Self::with_metadata(account_id, account_module, &BTreeMap::new(), assembler)
+    }
 
-        let mut procedure_digests = assembler
+    /// Creates and returns a new definition of an account's interface compiled from the specified
+    /// source code, attaching the provided metadata to the exported procedures with the
+    /// corresponding names. Procedures without an entry in `metadata` get empty metadata.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The account code could not be compiled.
+    /// - The account code exports more than [AccountCode::MAX_NUM_PROCEDURES] procedures.
+    /// - The assembler did not return a MAST root for each exported procedure, e.g., because the
+    ///   module re-exports procedures of other modules.
+    /// - The metadata references a procedure which is not exported by the account module.
+    pub fn with_metadata(
+        account_id: AccountId,
+        account_module: ModuleAst,
+        metadata: &BTreeMap<String, ProcedureMetadata>,
+        assembler: &Assembler,
+    ) -> Result<Self, AccountError> {
+        let module_path = Self::module_path(account_id);
+        let module =
+            Module::new(LibraryPath::new(&module_path).expect("valid path"), account_module);
+
+        let procedure_digests = assembler
             .compile_module(&module, &mut AssemblyContext::new(AssemblyContextType::Module))
             .map_err(AccountError::AccountCodeAssemblerError)?;
-        procedure_digests.sort_by_key(|a| a.as_bytes());
+        if procedure_digests.len() > Self::MAX_NUM_PROCEDURES {
+            return Err(AccountError::AccountCodeTooManyProcedures(
+                Self::MAX_NUM_PROCEDURES,
+                procedure_digests.len(),
+            ));
+        }
+
+        // the assembler returns the MAST roots of the exported procedures in the order in which
+        // the procedures are defined in the module
+        let exported_names = module
+            .ast
+            .procs()
+            .iter()
+            .filter(|proc| proc.is_export)
+            .map(|proc| proc.name.to_string())
+            .collect::<Vec<_>>();
+        if exported_names.len() != procedure_digests.len() {
+            return Err(AccountError::AccountCodeProcedureRootsMismatch(
+                exported_names.len(),
+                procedure_digests.len(),
+            ));
+        }
+        if let Some(name) = metadata.keys().find(|name| !exported_names.contains(name)) {
+            return Err(AccountError::AccountCodeProcedureNotFound(name.clone()));
+        }
+
+        let procedure_names =
+            exported_names.into_iter().zip(procedure_digests).collect::<BTreeMap<_, _>>();
+
+        let mut procedures = procedure_names
+            .iter()
+            .map(|(name, root)| (*root, metadata.get(name).copied().unwrap_or_default()))
+            .collect::<Vec<_>>();
+        procedures.sort_by_key(|(root, _)| root.as_bytes());
 
         Ok(Self {
             procedure_tree: SimpleSmt::with_leaves(
                 ACCOUNT_CODE_TREE_DEPTH,
-                procedure_digests
+                procedures
                     .iter()
                     .enumerate()
-                    .map(|(idx, p)| (idx as u64, p.into()))
+                    .map(|(idx, (root, metadata))| (idx as u64, metadata.procedure_leaf(*root)))
                     .collect::<Vec<_>>(),
             )
-            .unwrap(),
+            .expect("number of procedures does not exceed the number of tree leaves"),
             module: module.ast,
-            procedures: procedure_digests,
+            procedure_metadata: procedures.iter().map(|(_, metadata)| *metadata).collect(),
+            procedures: procedures.into_iter().map(|(root, _)| root).collect(),
+            procedure_names,
         })
     }
 
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
+    /// Returns the path of the module under which the code of the specified account is compiled,
+    /// e.g. `context::account_0x...`.
+    pub fn module_path(account_id: AccountId) -> String {
+        format!("{}_{}", Self::ACCOUNT_CODE_NAMESPACE_BASE, account_id)
+    }
+
     /// Returns a commitment to an account's public interface.
     pub fn root(&self) -> Digest {
         self.procedure_tree.root()
@@ -110,6 +186,18 @@ impl AccountCode {
         &self.procedures
     }
 
+    /// Returns a reference to the metadata of the account procedures, in the same order as the
+    /// procedure digests.
+    pub fn procedure_metadata(&self) -> &[ProcedureMetadata] {
+        &self.procedure_metadata
+    }
+
+    /// Returns an iterator over the names of the exported account procedures together with the
+    /// corresponding procedure digests, sorted by name.
+    pub fn procedure_names(&self) -> impl Iterator<Item = (&str, Digest)> {
+        self.procedure_names.iter().map(|(name, root)| (name.as_str(), *root))
+    }
+
     /// Returns a reference to the procedure tree.
     pub fn procedure_tree(&self) -> &SimpleSmt {
         &self.procedure_tree
@@ -138,6 +226,24 @@ impl AccountCode {
         *self.procedures.get(index).unwrap()
     }
 
+    /// Returns the digest of the exported procedure with the specified name or None if such
+    /// procedure is not defined for this account.
+    pub fn get_procedure_by_name(&self, name: &str) -> Option<Digest> {
+        self.procedure_names.get(name).copied()
+    }
+
+    /// Returns the name under which the procedure with the specified root is exported or None if
+    /// such procedure is not defined for this account.
+    ///
+    /// If the procedure is exported under several names, the first name in lexicographic order is
+    /// returned.
+    pub fn get_procedure_name_by_root(&self, root: Digest) -> Option<&str> {
+        self.procedure_names
+            .iter()
+            .find(|(_, r)| **r == root)
+            .map(|(name, _)| name.as_str())
+    }
+
     /// Returns the procedure index for the procedure with the specified root or None if such
     /// procedure is not defined for this account.
     pub fn get_procedure_index_by_root(&self, root: Digest) -> Option<usize> {
@@ -145,3 +251,80 @@ impl AccountCode {
         self.procedures.binary_search_by(|x| x.as_bytes().cmp(&root_bytes)).ok()
     }
 }
+
+// PROCEDURE METADATA
+// ================================================================================================
+
+/// Access control metadata of an account procedure.
+///
+/// The metadata is encoded as a word `[flags, 0, 0, 0]` and committed to in the leaf of the
+/// procedure in the account code tree. The transaction kernel enforces the metadata of the account
+/// procedure which invokes any of the kernel procedures authenticating their caller (e.g., updating
+/// account storage, account code, or the account nonce).
+///
+/// Currently the only flag restricts a procedure to the transaction script. Other flags, such as
+/// marking the authentication procedure of an account, are not defined yet as the kernel does not
+/// distinguish authentication procedures; new flags occupy the remaining bits of the flags element.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ProcedureMetadata {
+    tx_script_only: bool,
+}
+
+impl ProcedureMetadata {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Flag set for procedures which may be invoked only from the transaction script, and hence
+    /// not from note scripts.
+    pub const TX_SCRIPT_ONLY_FLAG: u64 = 1;
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns new procedure metadata.
+    pub const fn new(tx_script_only: bool) -> Self {
+        Self { tx_script_only }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns true if the procedure may be invoked only from the transaction script.
+    pub fn is_tx_script_only(&self) -> bool {
+        self.tx_script_only
+    }
+
+    /// Returns true if the metadata does not restrict the procedure in any way.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns the flags of this metadata.
+    pub fn flags(&self) -> u64 {
+        if self.tx_script_only {
+            Self::TX_SCRIPT_ONLY_FLAG
+        } else {
+            0
+        }
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the leaf of the account code tree for a procedure with the specified MAST root and
+    /// this metadata.
+    fn procedure_leaf(&self, root: Digest) -> Word {
+        if self.is_empty() {
+            root.into()
+        } else {
+            Hasher::merge(&[root, Word::from(*self).into()]).into()
+        }
+    }
+}
+
+impl From<ProcedureMetadata> for Word {
+    fn from(metadata: ProcedureMetadata) -> Self {
+        [Felt::new(metadata.flags()), ZERO, ZERO, ZERO]
+    }
+}
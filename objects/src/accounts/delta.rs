@@ -1,4 +1,4 @@
-use super::{AccountStorage, Felt};
+use super::{AccountStorage, Asset, BTreeMap, Felt, Vec, Word};
 use assembly::ast::ModuleAst;
 use crypto::merkle::{MerkleStoreDelta, MerkleTreeDelta};
 
@@ -11,13 +11,17 @@ use crypto::merkle::{MerkleStoreDelta, MerkleTreeDelta};
 /// - code: an Option<ModuleAst> that contains the updated code of the account.
 /// - nonce: if the nonce of the account has changed, the new nonce is stored here.
 /// - storage: an [AccountStorageDelta] that contains the changes to the account storage.
-/// - vault: an [MerkleTreeDelta] object that contains the changes to the account vault assets tree.
+/// - vault: an [AccountVaultDelta] object that contains the changes to the account vault assets.
+///
+/// The delta is sufficient to update a local copy of the initial account to its final state.
+/// Transaction results only carry the delta for accounts whose state is stored on-chain, see
+/// [crate::transaction::TransactionResult::account_delta()].
 #[derive(Debug, Clone)]
 pub struct AccountDelta {
     pub code: Option<ModuleAst>,
     pub nonce: Option<Felt>,
     pub storage: AccountStorageDelta,
-    pub vault: MerkleTreeDelta,
+    pub vault: AccountVaultDelta,
 }
 
 // ACCOUNT STORAGE DELTA
@@ -29,10 +33,14 @@ pub struct AccountDelta {
 /// The differences are represented as follows:
 /// - slots_delta: a `MerkleTreeDelta` that represents the changes to the account storage slots.
 /// - store_delta: a `MerkleStoreDelta` that represents the changes to the account storage store.
+/// - maps_delta: the items of the account storage maps which were updated, keyed by the index of
+///   the slot holding the map. Each item is a (key, new value) pair, where a value of [ZERO; 4]
+///   denotes an item which was removed from the map.
 #[derive(Debug, Clone)]
 pub struct AccountStorageDelta {
     pub slots_delta: MerkleTreeDelta,
     pub store_delta: MerkleStoreDelta,
+    pub maps_delta: BTreeMap<u8, Vec<(Word, Word)>>,
 }
 
 impl Default for AccountStorageDelta {
@@ -40,6 +48,32 @@ impl Default for AccountStorageDelta {
         Self {
             slots_delta: MerkleTreeDelta::new(AccountStorage::STORAGE_TREE_DEPTH),
             store_delta: MerkleStoreDelta::default(),
+            maps_delta: BTreeMap::new(),
         }
     }
 }
+
+// ACCOUNT VAULT DELTA
+// ================================================================================================
+
+/// [AccountVaultDelta] stores the difference between the initial and final account vault states.
+///
+/// The difference is represented as follows:
+/// - added_assets: a vector of assets that were added to the account vault. For fungible assets
+///   this is the amount by which the balance of the asset issued by the faucet increased.
+/// - removed_assets: a vector of assets that were removed from the account vault. For fungible
+///   assets this is the amount by which the balance of the asset issued by the faucet decreased.
+///
+/// A fungible asset issued by a given faucet appears in at most one of the two vectors.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccountVaultDelta {
+    pub added_assets: Vec<Asset>,
+    pub removed_assets: Vec<Asset>,
+}
+
+impl AccountVaultDelta {
+    /// Returns true if this vault delta contains no changes.
+    pub fn is_empty(&self) -> bool {
+        self.added_assets.is_empty() && self.removed_assets.is_empty()
+    }
+}
@@ -1,25 +1,32 @@
 use super::{
     assets::{Asset, FungibleAsset, NonFungibleAsset},
-    AccountError, AdviceInputsBuilder, Assembler, AssemblyContext, AssemblyContextType, Digest,
-    Felt, Hasher, LibraryPath, Module, ModuleAst, StarkField, TieredSmt, ToAdviceInputs, ToString,
-    Vec, Word, ZERO,
-};
-use crypto::{
-    merkle::StoreNode,
-    utils::collections::{ApplyDiff, TryApplyDiff},
+    AccountError, AdviceInputsBuilder, Assembler, AssemblyContext, AssemblyContextType, BTreeMap,
+    Digest, Felt, Hasher, LibraryPath, Module, ModuleAst, StarkField, String, TieredSmt,
+    ToAdviceInputs, ToString, Vec, Word, ZERO,
 };
+use crypto::{merkle::StoreNode, utils::collections::TryApplyDiff};
 
 mod account_id;
-pub use account_id::{validate_account_seed, AccountId, AccountType};
+pub use account_id::{
+    validate_account_seed, AccountId, AccountStorageMode, AccountType, NetworkId,
+};
+
+mod builder;
+pub use builder::AccountBuilder;
 
 mod code;
-pub use code::AccountCode;
+pub use code::{AccountCode, ProcedureMetadata};
+
+#[cfg(feature = "std")]
+mod seed;
+#[cfg(feature = "std")]
+pub use seed::{AccountSeedGrinder, CancellationToken, SeedGrindingOutcome};
 
 pub mod delta;
-pub use delta::{AccountDelta, AccountStorageDelta};
+pub use delta::{AccountDelta, AccountStorageDelta, AccountVaultDelta};
 
 mod storage;
-pub use storage::{AccountStorage, StorageItem};
+pub use storage::{AccountStorage, StorageItem, StorageMap, StorageSlotType};
 
 mod stub;
 pub use stub::AccountStub;
@@ -163,16 +170,18 @@ impl ToAdviceInputs for Account {
         target.push_onto_stack(&*self.storage.root());
         target.push_onto_stack(self.code.root().as_elements());
 
-        // extend the merkle store with the storage items
-        target.add_merkle_nodes(self.storage.slots().inner_nodes());
-        target.add_merkle_nodes(self.storage.store().inner_nodes());
+        // extend the advice provider with [AccountStorage] inputs
+        self.storage.to_advice_inputs(target);
 
         // extend the merkle store with account code tree
         target.add_merkle_nodes(self.code.procedure_tree().inner_nodes());
 
-        // extend advice map with (account proc root -> method tree index)
-        for (idx, leaf) in self.code.procedure_tree().leaves() {
-            target.insert_into_map(*leaf, vec![idx.into()]);
+        // extend advice map with (account proc root -> [method tree index, PROC_METADATA])
+        let procedures = self.code.procedures().iter().zip(self.code.procedure_metadata());
+        for (idx, (root, metadata)) in procedures.enumerate() {
+            let mut values = vec![Felt::new(idx as u64)];
+            values.extend_from_slice(&Word::from(*metadata));
+            target.insert_into_map((*root).into(), values);
         }
 
         // extend the advice provider with [AccountVault] inputs
@@ -217,7 +226,7 @@ impl TryApplyDiff<Digest, StoreNode> for Account {
         } = diff;
 
         self.storage.try_apply(storage)?;
-        self.vault.apply(vault);
+        self.vault.try_apply(vault)?;
 
         if let Some(nonce) = nonce {
             if nonce.as_int() <= self.nonce.as_int() {
@@ -0,0 +1,256 @@
+use super::{AccountId, AccountStorageMode, AccountType, Digest, Felt, Vec, Word};
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc,
+    },
+    thread,
+};
+
+// CANCELLATION TOKEN
+// ================================================================================================
+
+/// A token which can be used to cancel seed grinding from another thread.
+///
+/// Clones of the token share the same cancellation state.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Returns a new token which has not been cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels all grinding operations observing this token.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns true if this token has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+// GRINDING OUTCOME
+// ================================================================================================
+
+/// The outcome of an [AccountSeedGrinder::grind] call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeedGrindingOutcome {
+    /// A seed satisfying the account requirements was found.
+    Found(Word),
+    /// Grinding was cancelled before a seed was found. Grinding can be resumed by calling
+    /// [AccountSeedGrinder::grind] again.
+    Cancelled,
+}
+
+// ACCOUNT SEED GRINDER
+// ================================================================================================
+
+/// A multi-threaded grinder of account seeds.
+///
+/// Each worker thread walks its own chain of seeds in the same way as
+/// [AccountId::get_account_seed]: the seed following a non-matching seed is the digest computed
+/// from it. The chain of the first worker starts from the initial seed, so a grinder with a single
+/// worker finds the same seed as [AccountId::get_account_seed]. The chains of the other workers
+/// start from the initial seed with the worker index added to its first element.
+///
+/// The position of each worker in its chain is retained when grinding is cancelled, so that
+/// grinding can be resumed later, either by calling [AccountSeedGrinder::grind] again or by
+/// constructing a new grinder from the seeds returned by [AccountSeedGrinder::seeds].
+#[derive(Debug, Clone)]
+pub struct AccountSeedGrinder {
+    account_type: AccountType,
+    storage_mode: AccountStorageMode,
+    code_root: Digest,
+    storage_root: Digest,
+    seeds: Vec<Word>,
+    num_checked: u64,
+}
+
+impl AccountSeedGrinder {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The number of seeds a worker checks between two progress reports.
+    pub const PROGRESS_INTERVAL: u64 = 1 << 10;
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new grinder for seeds of accounts with the specified type, storage mode, code
+    /// root and storage root, starting from the provided initial seed.
+    ///
+    /// The grinder uses as many workers as there are CPUs available to the process.
+    pub fn new(
+        init_seed: [u8; 32],
+        account_type: AccountType,
+        storage_mode: AccountStorageMode,
+        code_root: Digest,
+        storage_root: Digest,
+    ) -> Self {
+        let num_workers = thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+        let init_seed: Vec<[u8; 8]> =
+            init_seed.chunks(8).map(|chunk| chunk.try_into().unwrap()).collect();
+        let init_seed: Word = [
+            Felt::from(init_seed[0]),
+            Felt::from(init_seed[1]),
+            Felt::from(init_seed[2]),
+            Felt::from(init_seed[3]),
+        ];
+
+        Self {
+            account_type,
+            storage_mode,
+            code_root,
+            storage_root,
+            seeds: worker_seeds(init_seed, num_workers),
+            num_checked: 0,
+        }
+    }
+
+    /// Returns a grinder which resumes grinding from the provided seeds, with one worker per seed.
+    ///
+    /// # Panics
+    /// Panics if no seeds are provided.
+    pub fn with_seeds(mut self, seeds: Vec<Word>) -> Self {
+        assert!(!seeds.is_empty(), "at least one seed must be provided");
+        self.seeds = seeds;
+        self
+    }
+
+    /// Returns a grinder which uses the specified number of workers, starting from the first seed
+    /// of this grinder.
+    ///
+    /// # Panics
+    /// Panics if the number of workers is zero.
+    pub fn with_num_workers(mut self, num_workers: usize) -> Self {
+        assert!(num_workers > 0, "at least one worker is required");
+        self.seeds = worker_seeds(self.seeds[0], num_workers);
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the seed from which each worker resumes grinding.
+    pub fn seeds(&self) -> &[Word] {
+        &self.seeds
+    }
+
+    /// Returns the total number of seeds checked by this grinder.
+    pub fn num_checked(&self) -> u64 {
+        self.num_checked
+    }
+
+    // GRINDING
+    // --------------------------------------------------------------------------------------------
+
+    /// Grinds seeds until a seed satisfying the account requirements is found or the provided
+    /// token is cancelled.
+    ///
+    /// The `progress` callback is invoked from the worker threads with the total number of seeds
+    /// checked by this grinder, roughly every [Self::PROGRESS_INTERVAL] seeds per worker.
+    pub fn grind<F>(&mut self, cancellation: &CancellationToken, progress: F) -> SeedGrindingOutcome
+    where
+        F: Fn(u64) + Sync,
+    {
+        let found = AtomicBool::new(false);
+        let num_checked = AtomicU64::new(self.num_checked);
+
+        let results = thread::scope(|scope| {
+            let workers = self
+                .seeds
+                .iter()
+                .map(|seed| {
+                    let worker = Worker {
+                        account_type: self.account_type,
+                        storage_mode: self.storage_mode,
+                        code_root: self.code_root,
+                        storage_root: self.storage_root,
+                        found: &found,
+                        num_checked: &num_checked,
+                        cancellation,
+                        progress: &progress,
+                    };
+                    scope.spawn(move || worker.run(*seed))
+                })
+                .collect::<Vec<_>>();
+
+            workers
+                .into_iter()
+                .map(|worker| worker.join().expect("seed grinding worker panicked"))
+                .collect::<Vec<_>>()
+        });
+
+        self.num_checked = num_checked.load(Ordering::Relaxed);
+        self.seeds = results.iter().map(|(seed, _)| *seed).collect();
+
+        match results.into_iter().find(|(_, is_match)| *is_match) {
+            Some((seed, _)) => SeedGrindingOutcome::Found(seed),
+            None => SeedGrindingOutcome::Cancelled,
+        }
+    }
+}
+
+// WORKER
+// ================================================================================================
+
+/// A single seed grinding thread together with the state it shares with the other workers.
+struct Worker<'a, F> {
+    account_type: AccountType,
+    storage_mode: AccountStorageMode,
+    code_root: Digest,
+    storage_root: Digest,
+    found: &'a AtomicBool,
+    num_checked: &'a AtomicU64,
+    cancellation: &'a CancellationToken,
+    progress: &'a F,
+}
+
+impl<'a, F: Fn(u64) + Sync> Worker<'a, F> {
+    /// Grinds the chain of seeds starting at the provided seed until a matching seed is found, or
+    /// another worker finds one, or grinding is cancelled. Returns the matching seed, or the next
+    /// seed to check if no match was found, together with a flag indicating whether it is a match.
+    fn run(self, seed: Word) -> (Word, bool) {
+        let mut current_seed = seed;
+        let mut num_unreported = 0;
+
+        loop {
+            let digest = AccountId::compute_digest(current_seed, self.code_root, self.storage_root);
+            num_unreported += 1;
+
+            if AccountId::seed_digest_matches(&digest, self.account_type, self.storage_mode) {
+                self.found.store(true, Ordering::Relaxed);
+                self.num_checked.fetch_add(num_unreported, Ordering::Relaxed);
+                return (current_seed, true);
+            }
+            if self.found.load(Ordering::Relaxed) || self.cancellation.is_cancelled() {
+                self.num_checked.fetch_add(num_unreported, Ordering::Relaxed);
+                return (digest.into(), false);
+            }
+            if num_unreported == AccountSeedGrinder::PROGRESS_INTERVAL {
+                let total = self.num_checked.fetch_add(num_unreported, Ordering::Relaxed);
+                (self.progress)(total + num_unreported);
+                num_unreported = 0;
+            }
+
+            current_seed = digest.into();
+        }
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns the starting seeds of the specified number of workers derived from the provided seed.
+fn worker_seeds(seed: Word, num_workers: usize) -> Vec<Word> {
+    (0..num_workers)
+        .map(|i| {
+            let mut worker_seed = seed;
+            worker_seed[0] += Felt::new(i as u64);
+            worker_seed
+        })
+        .collect()
+}
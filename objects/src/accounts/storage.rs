@@ -1,10 +1,95 @@
-use super::{AccountError, AccountStorageDelta, Digest, TryApplyDiff, Vec, Word};
+use super::{
+    AccountError, AccountStorageDelta, AdviceInputsBuilder, BTreeMap, Digest, Felt, Hasher,
+    TieredSmt, ToAdviceInputs, TryApplyDiff, Vec, Word,
+};
 use crypto::merkle::{MerkleStore, NodeIndex, SimpleSmt, StoreNode};
 
-// TYPE ALIASES
+// STORAGE SLOT TYPE
 // ================================================================================================
 
-pub type StorageItem = (u8, Word);
+/// The type of a storage slot which determines how the value of the slot is interpreted.
+///
+/// - Value: the slot holds a single word which can be set directly.
+/// - Map: the slot holds the root of a [StorageMap] and is updated via the map items.
+/// - Array: the slot holds the root of a sparse Merkle tree of the specified depth whose nodes are
+///   kept in the Merkle store of the account storage, and is updated via the items of the tree.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum StorageSlotType {
+    #[default]
+    Value,
+    Map,
+    Array {
+        depth: u8,
+    },
+}
+
+impl From<StorageSlotType> for Felt {
+    /// Encodes the slot type as a field element. The type is encoded in the least significant byte
+    /// (0 for value, 1 for map and 2 for array slots) and the depth of array slots in the next
+    /// byte.
+    fn from(slot_type: StorageSlotType) -> Self {
+        match slot_type {
+            StorageSlotType::Value => Felt::new(0),
+            StorageSlotType::Map => Felt::new(1),
+            StorageSlotType::Array { depth } => Felt::new(2 | ((depth as u64) << 8)),
+        }
+    }
+}
+
+// STORAGE ITEM
+// ================================================================================================
+
+/// An item of the account storage which consists of the index of the slot, the type of the slot
+/// and the value stored in the slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StorageItem {
+    index: u8,
+    slot_type: StorageSlotType,
+    value: Word,
+}
+
+impl StorageItem {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new item which stores the specified value in the value slot at the specified
+    /// index.
+    pub const fn new_value(index: u8, value: Word) -> Self {
+        Self {
+            index,
+            slot_type: StorageSlotType::Value,
+            value,
+        }
+    }
+
+    /// Returns a new item which stores the root of a sparse Merkle tree of the specified depth in
+    /// the array slot at the specified index.
+    pub const fn new_array(index: u8, depth: u8, root: Word) -> Self {
+        Self {
+            index,
+            slot_type: StorageSlotType::Array { depth },
+            value: root,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the index of the slot.
+    pub fn index(&self) -> u8 {
+        self.index
+    }
+
+    /// Returns the type of the slot.
+    pub fn slot_type(&self) -> StorageSlotType {
+        self.slot_type
+    }
+
+    /// Returns the value stored in the slot.
+    pub fn value(&self) -> Word {
+        self.value
+    }
+}
 
 // ACCOUNT STORAGE
 // ================================================================================================
@@ -17,10 +102,21 @@ pub type StorageItem = (u8, Word);
 /// is serialized it will check to see if any of the leafs in the simple sparse Merkle tree are
 /// Merkle roots of other Merkle structures.  If any Merkle roots are found then the Merkle
 /// structures will be persisted in the `AccountStorage` `MerkleStore`.
+///
+/// Slots can also hold key-value maps. The value of such a slot is the root of a [StorageMap], and
+/// the items of the map are accessed via [AccountStorage::get_map_item] and
+/// [AccountStorage::set_map_item].
+///
+/// The type of every slot is declared when the storage is created (see [StorageSlotType]). Unless
+/// all slots are value slots, a commitment to the slot types is stored in the reserved slot at
+/// index [AccountStorage::SLOT_TYPES_COMMITMENT_SLOT], which allows the transaction kernel to
+/// enforce the slot types.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct AccountStorage {
     slots: SimpleSmt,
+    slot_types: Vec<StorageSlotType>,
+    maps: BTreeMap<u8, StorageMap>,
     store: MerkleStore,
 }
 
@@ -31,20 +127,75 @@ impl AccountStorage {
     /// Depth of the storage tree.
     pub const STORAGE_TREE_DEPTH: u8 = 8;
 
+    /// Total number of storage slots.
+    pub const NUM_STORAGE_SLOTS: usize = 256;
+
+    /// Index of the storage slot at which faucet accounts track the issuance of their assets.
+    ///
+    /// For fungible faucets this is a value slot which holds the total issuance as the first
+    /// element of the slot value. For non-fungible faucets this is a map slot which maps each
+    /// issued asset to itself, such that an asset cannot be issued again until it is burned.
+    pub const FAUCET_ISSUANCE_SLOT: u8 = 254;
+
+    /// Index of the reserved storage slot which holds the commitment to the slot types.
+    ///
+    /// The commitment is a sequential hash of the slot types encoded as field elements, and is
+    /// [ZERO; 4] if all slots are value slots.
+    pub const SLOT_TYPES_COMMITMENT_SLOT: u8 = 255;
+
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
-    /// Returns a new instance of account storage initialized with the provided items.
+    /// Returns a new instance of account storage initialized with the provided items and maps.
+    ///
+    /// The slot of each map is set to the root of the map, and the types of all slots are
+    /// committed to in the slot at index [AccountStorage::SLOT_TYPES_COMMITMENT_SLOT].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - More than one item or map is provided for the same slot.
+    /// - An item or a map is provided for the reserved slot.
+    /// - An item is a map item, as maps must be provided together with their contents.
     pub fn new(
         items: Vec<StorageItem>,
+        maps: Vec<(u8, StorageMap)>,
         store: MerkleStore,
     ) -> Result<AccountStorage, AccountError> {
+        let mut slot_types = vec![StorageSlotType::Value; Self::NUM_STORAGE_SLOTS];
+        let mut leaves = Vec::with_capacity(items.len() + maps.len() + 1);
+
+        for item in items {
+            if item.slot_type == StorageSlotType::Map {
+                return Err(AccountError::StorageMapDataMissing(item.index));
+            }
+            slot_types[item.index as usize] = item.slot_type;
+            leaves.push((item.index, item.value));
+        }
+        for (index, map) in maps.iter() {
+            slot_types[*index as usize] = StorageSlotType::Map;
+            leaves.push((*index, *map.root()));
+        }
+
+        if leaves.iter().any(|(index, _)| *index == Self::SLOT_TYPES_COMMITMENT_SLOT) {
+            return Err(AccountError::StorageSlotIsReserved(Self::SLOT_TYPES_COMMITMENT_SLOT));
+        }
+        if let Some(commitment) = slot_types_commitment(&slot_types) {
+            leaves.push((Self::SLOT_TYPES_COMMITMENT_SLOT, *commitment));
+        }
+
         // construct storage slots smt
         let slots = SimpleSmt::with_leaves(
             Self::STORAGE_TREE_DEPTH,
-            items.into_iter().map(|x| (x.0 as u64, x.1)),
+            leaves.into_iter().map(|(index, value)| (index as u64, value)),
         )
         .map_err(AccountError::DuplicateStorageItems)?;
-        Ok(Self { slots, store })
+        let maps = maps.into_iter().collect();
+
+        Ok(Self {
+            slots,
+            slot_types,
+            maps,
+            store,
+        })
     }
 
     // PUBLIC ACCESSORS
@@ -64,45 +215,149 @@ impl AccountStorage {
         self.slots.get_node(item_index).expect("index is u8 - index within range")
     }
 
+    /// Returns the type of the slot at the specified index.
+    pub fn get_slot_type(&self, index: u8) -> StorageSlotType {
+        self.slot_types[index as usize]
+    }
+
+    /// Returns the types of all storage slots ordered by slot index.
+    pub fn slot_types(&self) -> &[StorageSlotType] {
+        &self.slot_types
+    }
+
+    /// Returns the commitment to the slot types stored in the reserved slot.
+    pub fn slot_types_commitment(&self) -> Digest {
+        self.get_item(Self::SLOT_TYPES_COMMITMENT_SLOT)
+    }
+
     /// Returns a reference to the sparse Merkle tree that backs the storage slots.
     pub fn slots(&self) -> &SimpleSmt {
         &self.slots
     }
 
+    /// Returns an item of the map stored at the specified slot index.
+    ///
+    /// If the key is not present in the map, [ZERO; 4] is returned.
+    ///
+    /// # Errors
+    /// Returns an error if the slot at the specified index does not hold a map.
+    pub fn get_map_item(&self, index: u8, key: Word) -> Result<Word, AccountError> {
+        self.maps
+            .get(&index)
+            .map(|map| map.get_value(key))
+            .ok_or(AccountError::StorageSlotNotMap(index))
+    }
+
     /// Returns a reference to the Merkle store that backs the storage.
     pub fn store(&self) -> &MerkleStore {
         &self.store
     }
 
-    /// Returns a list of items contained in this storage.
-    pub fn items(&self) -> &[Word] {
-        todo!()
+    /// Returns a reference to the maps held by this storage keyed by their slot index.
+    pub fn maps(&self) -> &BTreeMap<u8, StorageMap> {
+        &self.maps
+    }
+
+    /// Returns an iterator over the non-empty items contained in this storage, excluding the
+    /// reserved slot which holds the commitment to the slot types.
+    pub fn items(&self) -> impl Iterator<Item = StorageItem> + '_ {
+        self.slots
+            .leaves()
+            .filter(|(index, _)| *index != Self::SLOT_TYPES_COMMITMENT_SLOT as u64)
+            .map(|(index, value)| StorageItem {
+                index: index as u8,
+                slot_type: self.slot_types[index as usize],
+                value: *value,
+            })
     }
 
     // PUBLIC MODIFIERS
     // --------------------------------------------------------------------------------------------
-    /// Sets an item from the storage at the specified index.
-    pub fn set_item(&mut self, index: u8, value: Word) -> Word {
-        self.slots
-            .update_leaf(index as u64, value)
-            .expect("index is u8 - index within range")
+    /// Sets an item from the storage at the specified index. Returns the previous value of the
+    /// item.
+    ///
+    /// # Errors
+    /// Returns an error if the slot at the specified index is not a value slot or is reserved.
+    pub fn set_item(&mut self, index: u8, value: Word) -> Result<Word, AccountError> {
+        if index == Self::SLOT_TYPES_COMMITMENT_SLOT {
+            return Err(AccountError::StorageSlotIsReserved(index));
+        }
+        if self.get_slot_type(index) != StorageSlotType::Value {
+            return Err(AccountError::StorageSlotNotValue(index));
+        }
+        Ok(self.set_slot(index, value))
+    }
+
+    /// Sets an item of the map stored at the specified slot index and updates the slot to the new
+    /// root of the map. Returns the previous value of the item.
+    ///
+    /// # Errors
+    /// Returns an error if the slot at the specified index does not hold a map.
+    pub fn set_map_item(
+        &mut self,
+        index: u8,
+        key: Word,
+        value: Word,
+    ) -> Result<Word, AccountError> {
+        let map = self.maps.get_mut(&index).ok_or(AccountError::StorageSlotNotMap(index))?;
+        let old_value = map.insert(key, value);
+        let root = map.root();
+        self.set_slot(index, *root);
+        Ok(old_value)
     }
 
     /// Sets the node, specified by the slot index and node index, to the specified value.
+    ///
+    /// # Errors
+    /// Returns an error if the slot at the specified index is not an array slot or the node could
+    /// not be set.
     pub fn set_store_node(
         &mut self,
         slot_index: u8,
         index: NodeIndex,
         value: Digest,
     ) -> Result<Digest, AccountError> {
+        if !matches!(self.get_slot_type(slot_index), StorageSlotType::Array { .. }) {
+            return Err(AccountError::StorageSlotNotArray(slot_index));
+        }
         let root = self.get_item(slot_index);
         let root = self
             .store
             .set_node(root, index, value)
             .map_err(AccountError::SetStoreNodeFailed)?;
-        self.set_item(slot_index, *root.root);
+        self.set_slot(slot_index, *root.root);
         Ok(root.root)
     }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+    /// Sets the value of the slot at the specified index without checking the slot type.
+    fn set_slot(&mut self, index: u8, value: Word) -> Word {
+        self.slots
+            .update_leaf(index as u64, value)
+            .expect("index is u8 - index within range")
+    }
+}
+
+impl ToAdviceInputs for AccountStorage {
+    fn to_advice_inputs<T: AdviceInputsBuilder>(&self, target: &mut T) {
+        // extend the merkle store with the storage items
+        target.add_merkle_nodes(self.slots.inner_nodes());
+        target.add_merkle_nodes(self.store.inner_nodes());
+
+        // populate advice map with the slot types
+        if let Some(commitment) = slot_types_commitment(&self.slot_types) {
+            target.insert_into_map(
+                *commitment,
+                self.slot_types.iter().map(|t| (*t).into()).collect(),
+            );
+        }
+
+        // extend the advice provider with the storage maps
+        for map in self.maps.values() {
+            map.to_advice_inputs(target);
+        }
+    }
 }
 
 impl TryApplyDiff<Digest, StoreNode> for AccountStorage {
@@ -116,6 +371,102 @@ impl TryApplyDiff<Digest, StoreNode> for AccountStorage {
         self.store
             .try_apply(diff.store_delta)
             .map_err(AccountError::ApplyStorageStoreDiffFailed)?;
+
+        // apply the map deltas and make sure the maps are consistent with the updated slots
+        for (index, items) in diff.maps_delta {
+            let slot_value = self.get_item(index);
+            let map = self.maps.get_mut(&index).ok_or(AccountError::StorageSlotNotMap(index))?;
+            for (key, value) in items {
+                map.insert(key, value);
+            }
+            if map.root() != slot_value {
+                return Err(AccountError::ApplyStorageMapDiffFailed(index));
+            }
+        }
+
         Ok(())
     }
 }
+
+// HELPERS
+// ================================================================================================
+
+/// Returns the commitment to the provided slot types, or `None` if all slots are value slots.
+fn slot_types_commitment(slot_types: &[StorageSlotType]) -> Option<Digest> {
+    if slot_types.iter().all(|slot_type| *slot_type == StorageSlotType::Value) {
+        return None;
+    }
+    let elements = slot_types.iter().map(|slot_type| (*slot_type).into()).collect::<Vec<Felt>>();
+    Some(Hasher::hash_elements(&elements))
+}
+
+// STORAGE MAP
+// ================================================================================================
+
+/// A key-value map stored in a single slot of the account storage.
+///
+/// The map is backed by a tiered sparse Merkle tree in which both keys and values are words. The
+/// root of the tree is stored as the value of the slot holding the map.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct StorageMap {
+    map: TieredSmt,
+}
+
+impl StorageMap {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new empty storage map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a new storage map initialized with the provided entries.
+    ///
+    /// # Errors
+    /// Returns an error if the provided entries contain duplicate keys.
+    pub fn with_entries(
+        entries: impl IntoIterator<Item = (Word, Word)>,
+    ) -> Result<Self, AccountError> {
+        let map =
+            TieredSmt::with_leaves(entries.into_iter().map(|(key, value)| (key.into(), value)))
+                .map_err(AccountError::DuplicateStorageMapKeys)?;
+        Ok(Self { map })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the root of this map.
+    pub fn root(&self) -> Digest {
+        self.map.root()
+    }
+
+    /// Returns the value stored under the specified key.
+    ///
+    /// If the key is not present in the map, [ZERO; 4] is returned.
+    pub fn get_value(&self, key: Word) -> Word {
+        self.map.get_value(key.into())
+    }
+
+    // PUBLIC MODIFIERS
+    // --------------------------------------------------------------------------------------------
+    /// Inserts the value under the specified key and returns the previous value.
+    ///
+    /// Inserting [ZERO; 4] removes the key from the map.
+    pub fn insert(&mut self, key: Word, value: Word) -> Word {
+        self.map.insert(key.into(), value)
+    }
+}
+
+impl ToAdviceInputs for StorageMap {
+    fn to_advice_inputs<T: AdviceInputsBuilder>(&self, target: &mut T) {
+        // extend the merkle store with the map data
+        target.add_merkle_nodes(self.map.inner_nodes());
+
+        // populate advice map with tiered merkle tree leaf nodes
+        self.map.upper_leaves().for_each(|(node, key, value)| {
+            target.insert_into_map(*node, (*key).into_iter().chain(value).collect());
+        })
+    }
+}
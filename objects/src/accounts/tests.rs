@@ -1,9 +1,18 @@
-use super::{AccountId, AccountType};
+use super::{
+    account_id::{bech32_decode_values, bech32_encode},
+    AccountError, AccountId, AccountStorage, AccountStorageMode, AccountType, AccountVault,
+    AccountVaultDelta, Asset, Digest, Felt, FungibleAsset, Hasher, NetworkId, NonFungibleAsset,
+    StorageItem, StorageMap, StorageSlotType, TryApplyDiff, Word, ZERO,
+};
+use crate::assets::NonFungibleAssetDetails;
+use crypto::merkle::MerkleStore;
 
-const ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN: u64 = 0b0110011011u64 << 54;
-const ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN: u64 = 0b0001101110 << 54;
-const ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN: u64 = 0b1010011100 << 54;
-const ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN: u64 = 0b1101100110 << 54;
+const ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN: u64 = 0b0100110111u64 << 54;
+const ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN: u64 = 0b0011101110 << 54;
+const ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN: u64 = 0b1000111100 << 54;
+const ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN: u64 = 0b1111100110 << 54;
+const ACCOUNT_ID_REGULAR_ACCOUNT_ENCRYPTED: u64 = 0b0001011011 << 54;
+const ACCOUNT_ID_INVALID_STORAGE_MODE: u64 = 0b0010111011 << 54;
 
 #[test]
 fn test_account_tag_identifiers() {
@@ -11,23 +20,631 @@ fn test_account_tag_identifiers() {
         .expect("Valid account ID");
     assert!(account_id.is_regular_account());
     assert_eq!(account_id.account_type(), AccountType::RegularAccountImmutableCode);
+    assert_eq!(account_id.storage_mode(), Ok(AccountStorageMode::Public));
     assert!(account_id.is_on_chain());
 
     let account_id = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN)
         .expect("Valid account ID");
     assert!(account_id.is_regular_account());
     assert_eq!(account_id.account_type(), AccountType::RegularAccountUpdatableCode);
+    assert_eq!(account_id.storage_mode(), Ok(AccountStorageMode::Private));
     assert!(!account_id.is_on_chain());
 
     let account_id =
         AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).expect("Valid account ID");
     assert!(account_id.is_faucet());
     assert_eq!(account_id.account_type(), AccountType::FungibleFaucet);
+    assert_eq!(account_id.storage_mode(), Ok(AccountStorageMode::Public));
     assert!(account_id.is_on_chain());
 
     let account_id =
         AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN).expect("Valid account ID");
     assert!(account_id.is_faucet());
     assert_eq!(account_id.account_type(), AccountType::NonFungibleFaucet);
+    assert_eq!(account_id.storage_mode(), Ok(AccountStorageMode::Private));
     assert!(!account_id.is_on_chain());
+
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_ENCRYPTED).expect("Valid account ID");
+    assert_eq!(account_id.storage_mode(), Ok(AccountStorageMode::Encrypted));
+    assert!(account_id.is_on_chain());
+}
+
+#[test]
+fn test_account_id_invalid_storage_mode() {
+    assert_eq!(
+        AccountId::try_from(ACCOUNT_ID_INVALID_STORAGE_MODE),
+        Err(AccountError::AccountIdInvalidStorageMode)
+    );
+
+    // the storage mode of an unvalidated ID is reported as invalid rather than panicking
+    let account_id = AccountId::new_unchecked(Felt::new(ACCOUNT_ID_INVALID_STORAGE_MODE));
+    assert_eq!(account_id.storage_mode(), Err(AccountError::AccountIdInvalidStorageMode));
+    assert!(!account_id.is_on_chain());
+}
+
+#[test]
+fn test_account_vault_apply_delta() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset = |amount| Asset::from(FungibleAsset::new(faucet_id, amount).unwrap());
+
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+    let non_fungible_asset = |data: &[u8]| {
+        let details = NonFungibleAssetDetails::new(faucet_id, data.to_vec()).unwrap();
+        Asset::from(NonFungibleAsset::new(&details).unwrap())
+    };
+
+    let mut vault =
+        AccountVault::new(&[fungible_asset(100), non_fungible_asset(&[1, 2, 3])]).unwrap();
+
+    // increase the fungible balance, remove one non-fungible asset and add another one
+    let delta = AccountVaultDelta {
+        added_assets: vec![fungible_asset(50), non_fungible_asset(&[4, 5, 6])],
+        removed_assets: vec![non_fungible_asset(&[1, 2, 3])],
+    };
+    vault.try_apply(delta).unwrap();
+
+    let expected =
+        AccountVault::new(&[fungible_asset(150), non_fungible_asset(&[4, 5, 6])]).unwrap();
+    assert_eq!(vault.commitment(), expected.commitment());
+
+    // removing more than the available balance fails
+    let delta = AccountVaultDelta {
+        added_assets: vec![],
+        removed_assets: vec![fungible_asset(200)],
+    };
+    assert!(vault.try_apply(delta).is_err());
+}
+
+#[test]
+fn test_account_storage_map() {
+    const MAP_SLOT: u8 = 3;
+    const KEY_1: Word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    const KEY_2: Word = [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)];
+    const VALUE_1: Word = [Felt::new(9), Felt::new(10), Felt::new(11), Felt::new(12)];
+    const VALUE_2: Word = [Felt::new(13), Felt::new(14), Felt::new(15), Felt::new(16)];
+
+    let map = StorageMap::with_entries([(KEY_1, VALUE_1)]).unwrap();
+    let mut storage = AccountStorage::new(
+        vec![StorageItem::new_value(0, VALUE_1)],
+        vec![(MAP_SLOT, map.clone())],
+        MerkleStore::new(),
+    )
+    .unwrap();
+
+    // the slot holds the root of the map
+    assert_eq!(storage.get_item(MAP_SLOT), map.root());
+    assert_eq!(storage.get_map_item(MAP_SLOT, KEY_1).unwrap(), VALUE_1);
+    assert_eq!(storage.get_map_item(MAP_SLOT, KEY_2).unwrap(), [ZERO; 4]);
+
+    // setting a map item updates the slot
+    let old_value = storage.set_map_item(MAP_SLOT, KEY_2, VALUE_2).unwrap();
+    assert_eq!(old_value, [ZERO; 4]);
+    assert_eq!(storage.get_map_item(MAP_SLOT, KEY_2).unwrap(), VALUE_2);
+    assert_ne!(storage.get_item(MAP_SLOT), map.root());
+    assert_eq!(storage.get_item(MAP_SLOT), storage.maps()[&MAP_SLOT].root());
+
+    // slots which do not hold a map cannot be accessed as maps
+    assert!(matches!(
+        storage.get_map_item(0, KEY_1),
+        Err(AccountError::StorageSlotNotMap(0))
+    ));
+    assert!(matches!(
+        storage.set_map_item(1, KEY_1, VALUE_1),
+        Err(AccountError::StorageSlotNotMap(1))
+    ));
+
+    // a map cannot share a slot with an item
+    assert!(AccountStorage::new(
+        vec![StorageItem::new_value(MAP_SLOT, VALUE_1)],
+        vec![(MAP_SLOT, map)],
+        MerkleStore::new()
+    )
+    .is_err());
+}
+
+#[test]
+fn test_account_storage_slot_types() {
+    const ARRAY_SLOT: u8 = 2;
+    const MAP_SLOT: u8 = 3;
+    const VALUE: Word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+
+    // an all-value layout does not occupy the reserved slot
+    let mut storage =
+        AccountStorage::new(vec![StorageItem::new_value(0, VALUE)], vec![], MerkleStore::new())
+            .unwrap();
+    assert_eq!(storage.slot_types_commitment(), Digest::default());
+    assert_eq!(storage.items().collect::<Vec<_>>(), vec![StorageItem::new_value(0, VALUE)]);
+    assert_eq!(storage.set_item(1, VALUE).unwrap(), [ZERO; 4]);
+
+    // a typed layout is committed to in the reserved slot
+    let mut storage = AccountStorage::new(
+        vec![StorageItem::new_value(0, VALUE), StorageItem::new_array(ARRAY_SLOT, 64, VALUE)],
+        vec![(MAP_SLOT, StorageMap::new())],
+        MerkleStore::new(),
+    )
+    .unwrap();
+    assert_eq!(storage.get_slot_type(0), StorageSlotType::Value);
+    assert_eq!(storage.get_slot_type(ARRAY_SLOT), StorageSlotType::Array { depth: 64 });
+    assert_eq!(storage.get_slot_type(MAP_SLOT), StorageSlotType::Map);
+
+    let slot_types = storage.slot_types().iter().map(|t| (*t).into()).collect::<Vec<Felt>>();
+    assert_eq!(storage.slot_types_commitment(), Hasher::hash_elements(&slot_types));
+
+    // items do not include the reserved slot
+    let items = storage.items().collect::<Vec<_>>();
+    assert_eq!(items.len(), 3);
+    assert!(items
+        .iter()
+        .all(|item| item.index() != AccountStorage::SLOT_TYPES_COMMITMENT_SLOT));
+    assert_eq!(items[1], StorageItem::new_array(ARRAY_SLOT, 64, VALUE));
+
+    // only value slots can be set directly
+    assert!(storage.set_item(0, VALUE).is_ok());
+    assert!(matches!(
+        storage.set_item(ARRAY_SLOT, VALUE),
+        Err(AccountError::StorageSlotNotValue(ARRAY_SLOT))
+    ));
+    assert!(matches!(
+        storage.set_item(MAP_SLOT, VALUE),
+        Err(AccountError::StorageSlotNotValue(MAP_SLOT))
+    ));
+    assert!(matches!(
+        storage.set_item(AccountStorage::SLOT_TYPES_COMMITMENT_SLOT, VALUE),
+        Err(AccountError::StorageSlotIsReserved(_))
+    ));
+
+    // the reserved slot cannot be used
+    assert!(matches!(
+        AccountStorage::new(
+            vec![StorageItem::new_value(AccountStorage::SLOT_TYPES_COMMITMENT_SLOT, VALUE)],
+            vec![],
+            MerkleStore::new(),
+        ),
+        Err(AccountError::StorageSlotIsReserved(_))
+    ));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_account_seed_grinder_cancellation() {
+    use super::{AccountSeedGrinder, CancellationToken, SeedGrindingOutcome};
+
+    let mut grinder = AccountSeedGrinder::new(
+        [7; 32],
+        AccountType::FungibleFaucet,
+        AccountStorageMode::Public,
+        Digest::default(),
+        Digest::default(),
+    )
+    .with_num_workers(2);
+    let init_seeds = grinder.seeds().to_vec();
+    assert_eq!(init_seeds.len(), 2);
+    assert_ne!(init_seeds[0], init_seeds[1]);
+
+    // cancel grinding as soon as the first progress report is received
+    let cancellation = CancellationToken::new();
+    let outcome = grinder.grind(&cancellation, |_| cancellation.cancel());
+    assert_eq!(outcome, SeedGrindingOutcome::Cancelled);
+    assert!(grinder.num_checked() >= AccountSeedGrinder::PROGRESS_INTERVAL);
+
+    // the workers advanced along their chains and grinding resumes from there
+    let seeds = grinder.seeds().to_vec();
+    assert_eq!(seeds.len(), 2);
+    assert_ne!(seeds, init_seeds);
+
+    let num_checked = grinder.num_checked();
+    let outcome = grinder.grind(&cancellation, |_| {});
+    assert_eq!(outcome, SeedGrindingOutcome::Cancelled);
+    assert_eq!(grinder.num_checked(), num_checked + 2);
+
+    // a grinder can be restored from the seeds of a previous grinder
+    let grinder = AccountSeedGrinder::new(
+        [7; 32],
+        AccountType::FungibleFaucet,
+        AccountStorageMode::Public,
+        Digest::default(),
+        Digest::default(),
+    )
+    .with_seeds(seeds.clone());
+    assert_eq!(grinder.seeds(), &seeds[..]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_account_seed_grinder_finds_seed() {
+    use super::{AccountSeedGrinder, CancellationToken, SeedGrindingOutcome};
+    use crate::mock::{
+        assembler, mock_new_account, ACCOUNT_SEED_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+    };
+
+    let account = mock_new_account(&mut assembler());
+    let code_root = account.code().root();
+    let storage_root = account.storage().root();
+    let init_seed = mock_new_account_init_seed();
+
+    let expected_seed = AccountId::get_account_seed(
+        init_seed,
+        AccountType::RegularAccountUpdatableCode,
+        AccountStorageMode::Private,
+        code_root,
+        storage_root,
+    )
+    .unwrap();
+    let mock_seed = ACCOUNT_SEED_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN.map(Felt::new);
+    assert_eq!(expected_seed, mock_seed);
+
+    // the grinder finds the same seed as the single-threaded seed generator
+    for num_workers in [1, 4] {
+        let mut grinder = AccountSeedGrinder::new(
+            init_seed,
+            AccountType::RegularAccountUpdatableCode,
+            AccountStorageMode::Private,
+            code_root,
+            storage_root,
+        )
+        .with_num_workers(num_workers);
+        let outcome = grinder.grind(&CancellationToken::new(), |_| {});
+        assert_eq!(outcome, SeedGrindingOutcome::Found(expected_seed));
+    }
+
+    // the seed derives a valid account ID
+    let digest = AccountId::compute_digest(expected_seed, code_root, storage_root);
+    assert!(AccountId::validate_seed_digest(&digest).is_ok());
+    assert_eq!(AccountId::new(expected_seed, code_root, storage_root), Ok(account.id()));
+}
+
+#[test]
+fn test_account_id_bech32() {
+    let account_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+
+    // account IDs round-trip through their bech32 encoding for all networks
+    for network in [NetworkId::Mainnet, NetworkId::Testnet, NetworkId::Devnet] {
+        let encoded = account_id.to_bech32(network);
+        assert!(encoded.starts_with(network.hrp()));
+        assert_eq!(AccountId::from_bech32(&encoded, network).unwrap(), account_id);
+        assert_eq!(encoded.parse::<AccountId>().unwrap(), account_id);
+        assert_eq!(encoded.to_uppercase().parse::<AccountId>().unwrap(), account_id);
+    }
+
+    // a typo invalidates the checksum
+    let encoded = account_id.to_bech32(NetworkId::Mainnet);
+    let mut typo = encoded.clone().into_bytes();
+    typo[5] = if typo[5] == b'q' { b'p' } else { b'q' };
+    let typo = String::from_utf8(typo).unwrap();
+    assert_eq!(typo.parse::<AccountId>(), Err(AccountError::AccountIdBech32InvalidChecksum));
+
+    // the network must match
+    assert_eq!(
+        AccountId::from_bech32(&encoded, NetworkId::Testnet),
+        Err(AccountError::AccountIdBech32UnexpectedNetwork("mm".into()))
+    );
+    let unknown_network = bech32_encode("xyz", &<[u8; 8]>::from(account_id));
+    assert_eq!(
+        unknown_network.parse::<AccountId>(),
+        Err(AccountError::AccountIdBech32UnexpectedNetwork("xyz".into()))
+    );
+
+    // the encoded bytes must be a valid field element
+    let invalid_felt = bech32_encode(NetworkId::Mainnet.hrp(), &[u8::MAX; 8]);
+    assert!(matches!(
+        invalid_felt.parse::<AccountId>(),
+        Err(AccountError::AccountIdInvalidFieldElement(_))
+    ));
+
+    // malformed encodings are rejected
+    assert!(matches!(
+        "mm1".parse::<AccountId>(),
+        Err(AccountError::AccountIdBech32Malformed(_))
+    ));
+    assert!(matches!(
+        encoded.replacen("mm1", "mM1", 1).parse::<AccountId>(),
+        Err(AccountError::AccountIdBech32Malformed(_))
+    ));
+}
+
+#[test]
+fn test_bech32m_test_vectors() {
+    // valid bech32m strings from BIP-350
+    let valid = [
+        "A1LQFN3A",
+        "a1lqfn3a",
+        "an83characterlonghumanreadablepartthatcontainsthetheexcludedcharactersbioandnumber11sg7hg6",
+        "abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryx",
+        "11lllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllludsr8",
+        "split1checkupstagehandshakeupstreamerranterredcaperredlc445v",
+        "?1v759aa",
+    ];
+    for encoded in valid {
+        let (hrp, values) = bech32_decode_values(encoded).unwrap();
+        let separator = encoded.rfind('1').unwrap();
+        assert_eq!(hrp, encoded[..separator].to_lowercase());
+        assert_eq!(values.len(), encoded.len() - separator - 7);
+    }
+
+    // invalid bech32m strings from BIP-350
+    let invalid = [
+        "\x201xj0phk",
+        "\x7f1g6xzxy",
+        "\u{80}1vctc34",
+        "an84characterslonghumanreadablepartthatcontainsthetheexcludedcharactersbioandnumber11d6pts4",
+        "qyrz8wqd2c9m",
+        "1qyrz8wqd2c9m",
+        "y1b0jsk6g",
+        "lt1igcx5c0",
+        "in1muywd",
+        "mm1crxm3i",
+        "au1s5cgom",
+        "M1VUXWEZ",
+        "16plkw9",
+        "1p2gdwpf",
+    ];
+    for encoded in invalid {
+        assert!(bech32_decode_values(encoded).is_err(), "{encoded:?} must be rejected");
+    }
+
+    // re-encoding the data of a valid string reproduces the string, including its checksum
+    let encoded = "abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryx";
+    let (hrp, values) = bech32_decode_values(encoded).unwrap();
+    let bits = values.iter().flat_map(|v| (0..5).rev().map(move |i| (v >> i) & 1));
+    let data = bits
+        .collect::<Vec<u8>>()
+        .chunks(8)
+        .map(|byte| byte.iter().fold(0, |acc, bit| (acc << 1) | bit))
+        .collect::<Vec<u8>>();
+    assert_eq!(bech32_encode(&hrp, &data), encoded);
+}
+
+#[test]
+fn test_account_builder_build() {
+    use super::{validate_account_seed, AccountBuilder};
+    use crate::mock::{
+        assembler, mock_new_account, ACCOUNT_SEED_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+    };
+
+    let mut assembler = assembler();
+    let mock_account = mock_new_account(&mut assembler);
+
+    // the account is built from the code and storage of the mock new account, so that the seed
+    // of the mock account is found without grinding
+    let storage = mock_account.storage();
+    let builder =
+        AccountBuilder::new(mock_new_account_init_seed(), mock_account.code().module().clone())
+            .account_type(AccountType::RegularAccountUpdatableCode)
+            .storage_mode(AccountStorageMode::Private)
+            .merkle_store(storage.store().clone());
+    let builder = storage.items().fold(builder, |builder, item| builder.storage_item(item));
+    let (account, seed) = builder.build(&assembler).unwrap();
+
+    // the ID is derived from the returned seed and the code and storage of the account
+    assert_eq!(seed, ACCOUNT_SEED_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN.map(Felt::new));
+    assert_eq!(account.id(), mock_account.id());
+    assert!(validate_account_seed(&account, seed).is_ok());
+    assert_eq!(account.code().root(), mock_account.code().root());
+    assert_eq!(account.storage().root(), mock_account.storage().root());
+
+    // new accounts have an empty vault and a nonce of zero
+    assert_eq!(account.vault().assets().count(), 0);
+    assert_eq!(account.nonce(), ZERO);
+    assert!(account.is_new());
+}
+
+#[test]
+fn test_account_builder_account_seed() {
+    use super::AccountBuilder;
+    use crate::mock::{
+        assembler, mock_new_account, ACCOUNT_SEED_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+    };
+
+    let mut assembler = assembler();
+    let mock_account = mock_new_account(&mut assembler);
+    let seed = ACCOUNT_SEED_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN.map(Felt::new);
+
+    let storage = mock_account.storage();
+    let builder = |storage_mode: AccountStorageMode| {
+        let builder = AccountBuilder::new([0; 32], mock_account.code().module().clone())
+            .account_type(AccountType::RegularAccountUpdatableCode)
+            .storage_mode(storage_mode)
+            .merkle_store(storage.store().clone())
+            .account_seed(seed);
+        storage.items().fold(builder, |builder, item| builder.storage_item(item))
+    };
+
+    // the precomputed seed is used instead of grinding one from the init seed
+    let (account, account_seed) = builder(AccountStorageMode::Private).build(&assembler).unwrap();
+    assert_eq!(account_seed, seed);
+    assert_eq!(account.id(), mock_account.id());
+
+    // the seed must derive an ID of the specified type and storage mode
+    let result = builder(AccountStorageMode::Public).build(&assembler);
+    assert_eq!(result, Err(AccountError::AccountIdSeedMismatch(seed)));
+}
+
+#[test]
+fn test_account_builder_rejects_code_updates() {
+    use super::{AccountBuilder, ModuleAst};
+    use crate::mock::assembler;
+
+    let assembler = assembler();
+    let code = ModuleAst::parse(
+        "
+        use.miden::sat::account
+
+        export.update_code
+            exec.account::set_code
+        end
+        ",
+    )
+    .unwrap();
+
+    // only regular accounts with updatable code may update their code
+    for account_type in [
+        AccountType::RegularAccountImmutableCode,
+        AccountType::FungibleFaucet,
+        AccountType::NonFungibleFaucet,
+    ] {
+        let result = AccountBuilder::new([0; 32], code.clone())
+            .account_type(account_type)
+            .build(&assembler);
+        assert_eq!(result, Err(AccountError::AccountCodeUpdatesNotSupported(account_type)));
+    }
+
+    // storage errors are reported as well
+    let result = AccountBuilder::new([0; 32], code)
+        .storage_item(StorageItem::new_value(AccountStorage::SLOT_TYPES_COMMITMENT_SLOT, [ZERO; 4]))
+        .build(&assembler);
+    assert_eq!(
+        result,
+        Err(AccountError::StorageSlotIsReserved(AccountStorage::SLOT_TYPES_COMMITMENT_SLOT))
+    );
+}
+
+#[test]
+fn test_account_code_procedure_metadata() {
+    use super::{AccountCode, BTreeMap, ModuleAst, ProcedureMetadata, ToString};
+    use crate::mock::assembler;
+
+    let assembler = assembler();
+    let account_id = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN)
+        .expect("Valid account ID");
+    let module = ModuleAst::parse(
+        "
+        export.foo
+            push.1 drop
+        end
+
+        export.bar
+            push.2 drop
+        end
+        ",
+    )
+    .unwrap();
+
+    let tx_script_only = ProcedureMetadata::new(true);
+    assert!(ProcedureMetadata::default().is_empty());
+    assert_eq!(Word::from(tx_script_only), [Felt::new(1), ZERO, ZERO, ZERO]);
+
+    let code = AccountCode::new(account_id, module.clone(), &assembler).unwrap();
+    assert!(code.procedure_metadata().iter().all(ProcedureMetadata::is_empty));
+
+    // the metadata is committed to in the code root
+    let metadata = BTreeMap::from([("bar".to_string(), tx_script_only)]);
+    let code_with_metadata =
+        AccountCode::with_metadata(account_id, module.clone(), &metadata, &assembler).unwrap();
+    assert_eq!(code_with_metadata.procedures(), code.procedures());
+    assert_ne!(code_with_metadata.root(), code.root());
+
+    // the metadata is attached to the MAST root of `bar`, which is the root of a program with the
+    // same body
+    let bar_root = assembler.compile("begin push.2 drop end").unwrap().hash();
+    let flagged_roots = code_with_metadata
+        .procedures()
+        .iter()
+        .zip(code_with_metadata.procedure_metadata())
+        .filter(|(_, metadata)| metadata.is_tx_script_only())
+        .map(|(root, _)| *root)
+        .collect::<Vec<_>>();
+    assert_eq!(flagged_roots, [bar_root]);
+
+    // the metadata must reference exported procedures
+    let metadata = BTreeMap::from([("baz".to_string(), tx_script_only)]);
+    assert_eq!(
+        AccountCode::with_metadata(account_id, module, &metadata, &assembler),
+        Err(AccountError::AccountCodeProcedureNotFound("baz".to_string()))
+    );
+}
+
+#[test]
+fn test_account_code_procedure_names() {
+    use super::{AccountCode, ModuleAst};
+    use crate::mock::assembler;
+
+    let account_id = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN)
+        .expect("Valid account ID");
+    let module = ModuleAst::parse(
+        "
+        export.foo
+            push.1 drop
+        end
+
+        proc.helper
+            push.2 drop
+        end
+
+        export.bar
+            exec.helper
+        end
+        ",
+    )
+    .unwrap();
+    let assembler = assembler();
+    let code = AccountCode::new(account_id, module, &assembler).unwrap();
+
+    // procedure names resolve to the MAST roots of their bodies, which are the roots of programs
+    // with the same bodies
+    let foo_root = assembler.compile("begin push.1 drop end").unwrap().hash();
+    let bar_root = assembler.compile("begin push.2 drop end").unwrap().hash();
+    assert_ne!(foo_root, bar_root);
+    assert_eq!(code.get_procedure_by_name("foo"), Some(foo_root));
+    assert_eq!(code.get_procedure_by_name("bar"), Some(bar_root));
+
+    // only exported procedures are indexed
+    let names = code.procedure_names().map(|(name, _)| name).collect::<Vec<_>>();
+    assert_eq!(names, ["bar", "foo"]);
+    assert_eq!(code.get_procedure_by_name("helper"), None);
+
+    for (name, root) in code.procedure_names() {
+        assert!(code.has_procedure(root));
+        assert_eq!(code.get_procedure_by_name(name), Some(root));
+        assert_eq!(code.get_procedure_name_by_root(root), Some(name));
+    }
+    assert_eq!(code.get_procedure_name_by_root(Digest::default()), None);
+}
+
+#[test]
+fn test_account_code_too_many_procedures() {
+    use super::{AccountCode, ModuleAst};
+    use crate::mock::assembler;
+
+    let account_id = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN)
+        .expect("Valid account ID");
+    let assembler = assembler();
+    let module_source = |num_procedures: usize| {
+        (0..num_procedures)
+            .map(|i| format!("export.proc_{i}\n    push.{i} drop\nend\n"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    let module = ModuleAst::parse(&module_source(AccountCode::MAX_NUM_PROCEDURES)).unwrap();
+    let code = AccountCode::new(account_id, module, &assembler).unwrap();
+    assert_eq!(code.num_procedures(), AccountCode::MAX_NUM_PROCEDURES);
+
+    let module = ModuleAst::parse(&module_source(AccountCode::MAX_NUM_PROCEDURES + 1)).unwrap();
+    assert_eq!(
+        AccountCode::new(account_id, module, &assembler),
+        Err(AccountError::AccountCodeTooManyProcedures(
+            AccountCode::MAX_NUM_PROCEDURES,
+            AccountCode::MAX_NUM_PROCEDURES + 1,
+        ))
+    );
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns the initial seed whose field elements are the precomputed seed of the mock new account.
+///
+/// Grinding a fresh seed takes too long for a unit test, so seed generation in tests starts from
+/// the seed of the mock new account, which immediately satisfies the requirements of its account
+/// type and storage mode.
+fn mock_new_account_init_seed() -> [u8; 32] {
+    use crate::mock::ACCOUNT_SEED_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN;
+
+    let seed = ACCOUNT_SEED_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN;
+    let mut init_seed = [0; 32];
+    for (bytes, element) in init_seed.chunks_mut(8).zip(seed) {
+        bytes.copy_from_slice(&element.to_le_bytes());
+    }
+    init_seed
 }
@@ -1,8 +1,7 @@
 use super::{
-    AccountError, AccountId, AccountType, AdviceInputsBuilder, ApplyDiff, Asset, Digest,
-    FungibleAsset, NonFungibleAsset, StoreNode, TieredSmt, ToAdviceInputs, ZERO,
+    AccountError, AccountId, AccountType, AccountVaultDelta, AdviceInputsBuilder, Asset, Digest,
+    FungibleAsset, NonFungibleAsset, StoreNode, TieredSmt, ToAdviceInputs, TryApplyDiff, ZERO,
 };
-use crypto::merkle::MerkleTreeDelta;
 
 // ACCOUNT VAULT
 // ================================================================================================
@@ -108,7 +107,7 @@ impl AccountVault {
             current if current == TieredSmt::EMPTY_VALUE => asset,
             current => {
                 let current = FungibleAsset::new_unchecked(current);
-                current.add(asset).map_err(AccountError::AddFungibleAssetBalanceError)?
+                current.merge(asset).map_err(AccountError::AddFungibleAssetBalanceError)?
             }
         };
         self.asset_tree.insert(new.vault_key().into(), new.into());
@@ -161,7 +160,7 @@ impl AccountVault {
         asset: FungibleAsset,
     ) -> Result<FungibleAsset, AccountError> {
         // fetch the asset from the vault.
-        let mut current = match self.asset_tree.get_value(asset.vault_key().into()) {
+        let current = match self.asset_tree.get_value(asset.vault_key().into()) {
             current if current == TieredSmt::EMPTY_VALUE => {
                 return Err(AccountError::FungibleAssetNotFound(asset))
             }
@@ -169,8 +168,8 @@ impl AccountVault {
         };
 
         // subtract the amount of the asset to be removed from the current amount.
-        current
-            .sub(asset.amount())
+        let current = current
+            .checked_sub(asset.amount())
             .map_err(AccountError::SubtractFungibleAssetBalanceError)?;
 
         // if the amount of the asset is zero, remove the asset from the vault.
@@ -219,9 +218,25 @@ impl ToAdviceInputs for AccountVault {
 
 // DIFF
 // ================================================================================================
-impl ApplyDiff<Digest, StoreNode> for AccountVault {
-    type DiffType = MerkleTreeDelta;
+impl TryApplyDiff<Digest, StoreNode> for AccountVault {
+    type DiffType = AccountVaultDelta;
+    type Error = AccountError;
 
-    // TODO: Must find a way to apply this diff
-    fn apply(&mut self, _diff: MerkleTreeDelta) {}
+    /// Applies the provided vault delta to this vault by first removing the removed assets and
+    /// then adding the added assets.
+    ///
+    /// # Errors
+    /// Returns an error if any of the assets could not be removed from or added to the vault. In
+    /// this case the vault may be left in a partially updated state.
+    fn try_apply(&mut self, diff: Self::DiffType) -> Result<(), Self::Error> {
+        for asset in diff.removed_assets {
+            self.remove_asset(asset)?;
+        }
+
+        for asset in diff.added_assets {
+            self.add_asset(asset)?;
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,200 @@
+use super::{AccountId, Asset, AssetError, BTreeMap, FungibleAsset, NonFungibleAsset, Vec};
+use crate::{notes::NoteVault, AccountError, AccountVault, NoteError};
+
+// ASSET BUNDLE
+// ================================================================================================
+
+/// A collection of fungible and non-fungible assets.
+///
+/// Unlike account and note vaults, an asset bundle merges fungible assets issued by the same
+/// faucet into a single asset holding the total amount. Non-fungible assets are kept unique.
+/// Fungible assets with a zero amount are not retained in the bundle.
+///
+/// An asset bundle can be converted into either an [AccountVault] or a [NoteVault].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AssetBundle {
+    fungible_assets: BTreeMap<AccountId, FungibleAsset>,
+    non_fungible_assets: BTreeMap<[u8; 32], NonFungibleAsset>,
+}
+
+impl AssetBundle {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new empty [AssetBundle].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns an asset bundle containing the provided assets.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The total amount of fungible assets issued by the same faucet is greater than
+    ///   [FungibleAsset::MAX_AMOUNT].
+    /// - The same non-fungible asset is provided more than once.
+    pub fn from_assets<I>(assets: I) -> Result<Self, AssetError>
+    where
+        I: IntoIterator<Item = Asset>,
+    {
+        let mut bundle = Self::new();
+        for asset in assets {
+            bundle.add_asset(asset)?;
+        }
+
+        Ok(bundle)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of assets in this bundle.
+    pub fn num_assets(&self) -> usize {
+        self.fungible_assets.len() + self.non_fungible_assets.len()
+    }
+
+    /// Returns true if this bundle does not contain any assets.
+    pub fn is_empty(&self) -> bool {
+        self.num_assets() == 0
+    }
+
+    /// Returns the amount of the asset issued by the specified faucet held by this bundle.
+    pub fn get_balance(&self, faucet_id: AccountId) -> u64 {
+        self.fungible_assets.get(&faucet_id).map_or(0, FungibleAsset::amount)
+    }
+
+    /// Returns true if this bundle holds the specified asset. For fungible assets, the bundle must
+    /// hold at least the amount of the specified asset.
+    pub fn contains_asset(&self, asset: &Asset) -> bool {
+        match asset {
+            Asset::Fungible(asset) => self.get_balance(asset.faucet_id()) >= asset.amount(),
+            Asset::NonFungible(asset) => {
+                self.non_fungible_assets.contains_key(&<[u8; 32]>::from(*asset))
+            }
+        }
+    }
+
+    /// Returns true if this bundle covers the other bundle, i.e., if it holds all non-fungible
+    /// assets of the other bundle and at least the amount of each of its fungible assets.
+    pub fn contains(&self, other: &Self) -> bool {
+        other.iter().all(|asset| self.contains_asset(&asset))
+    }
+
+    /// Returns an iterator over the assets of this bundle. Fungible assets are returned first,
+    /// ordered by faucet ID.
+    pub fn iter(&self) -> impl Iterator<Item = Asset> + '_ {
+        self.fungible_assets
+            .values()
+            .map(|asset| Asset::from(*asset))
+            .chain(self.non_fungible_assets.values().map(|asset| Asset::from(*asset)))
+    }
+
+    // MODIFIERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds the specified asset to this bundle. A fungible asset is merged with the asset issued
+    /// by the same faucet already held by this bundle.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The total amount of the fungible asset is greater than [FungibleAsset::MAX_AMOUNT].
+    /// - The non-fungible asset is already in this bundle.
+    pub fn add_asset(&mut self, asset: Asset) -> Result<(), AssetError> {
+        match asset {
+            Asset::Fungible(asset) => {
+                let total = match self.fungible_assets.get(&asset.faucet_id()) {
+                    Some(current) => current.merge(asset)?,
+                    None => asset,
+                };
+                if total.amount() != 0 {
+                    self.fungible_assets.insert(total.faucet_id(), total);
+                }
+            }
+            Asset::NonFungible(asset) => {
+                if self.non_fungible_assets.insert(asset.into(), asset).is_some() {
+                    return Err(AssetError::duplicate_non_fungible_asset(asset));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes the specified asset from this bundle. For fungible assets, the amount of the
+    /// specified asset is subtracted from the amount held by this bundle.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The bundle holds less than the amount of the fungible asset.
+    /// - The non-fungible asset is not in this bundle.
+    pub fn remove_asset(&mut self, asset: Asset) -> Result<(), AssetError> {
+        match asset {
+            Asset::Fungible(asset) => {
+                let current = self.get_balance(asset.faucet_id());
+                let remainder =
+                    FungibleAsset::new(asset.faucet_id(), current)?.checked_sub(asset.amount())?;
+                if remainder.amount() == 0 {
+                    self.fungible_assets.remove(&asset.faucet_id());
+                } else {
+                    self.fungible_assets.insert(asset.faucet_id(), remainder);
+                }
+            }
+            Asset::NonFungible(asset) => {
+                if self.non_fungible_assets.remove(&<[u8; 32]>::from(asset)).is_none() {
+                    return Err(AssetError::non_fungible_asset_not_found(asset));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Adds all assets of the other bundle to this bundle.
+    ///
+    /// # Errors
+    /// Returns an error if any of the assets could not be added as described in
+    /// [AssetBundle::add_asset()]. In this case, this bundle may be partially updated.
+    pub fn merge(&mut self, other: &Self) -> Result<(), AssetError> {
+        other.iter().try_for_each(|asset| self.add_asset(asset))
+    }
+
+    /// Returns a new bundle with the assets of the other bundle removed from this bundle.
+    ///
+    /// # Errors
+    /// Returns an error if this bundle does not cover the other bundle.
+    pub fn checked_sub(&self, other: &Self) -> Result<Self, AssetError> {
+        let mut result = self.clone();
+        other.iter().try_for_each(|asset| result.remove_asset(asset))?;
+
+        Ok(result)
+    }
+}
+
+impl TryFrom<&[Asset]> for AssetBundle {
+    type Error = AssetError;
+
+    fn try_from(assets: &[Asset]) -> Result<Self, Self::Error> {
+        Self::from_assets(assets.iter().copied())
+    }
+}
+
+impl From<AssetBundle> for Vec<Asset> {
+    fn from(bundle: AssetBundle) -> Self {
+        bundle.iter().collect()
+    }
+}
+
+impl TryFrom<AssetBundle> for AccountVault {
+    type Error = AccountError;
+
+    fn try_from(bundle: AssetBundle) -> Result<Self, Self::Error> {
+        AccountVault::new(&Vec::from(bundle))
+    }
+}
+
+impl TryFrom<AssetBundle> for NoteVault {
+    type Error = NoteError;
+
+    fn try_from(bundle: AssetBundle) -> Result<Self, Self::Error> {
+        NoteVault::new(&Vec::from(bundle))
+    }
+}
@@ -1,8 +1,20 @@
 use super::{
-    AccountId, AccountType, AssetError, Felt, Hasher, StarkField, ToString, Vec, Word, ZERO,
+    AccountId, AccountType, AssetError, BTreeMap, Felt, Hasher, StarkField, String, ToString, Vec,
+    Word, ZERO,
 };
+use assembly::utils::IntoBytes;
 use core::{fmt, ops::Deref};
 
+mod bundle;
+pub use bundle::AssetBundle;
+
+mod token;
+pub use token::TokenMetadata;
+pub(crate) use token::{encode_token_symbol, validate_token_decimals};
+
+#[cfg(test)]
+mod tests;
+
 // ASSET
 // ================================================================================================
 
@@ -37,8 +49,9 @@ use core::{fmt, ops::Deref};
 ///
 /// # Non-fungible assets
 /// The 4 elements of non-fungible assets are computed as follows:
-/// - First the asset data is hashed. This compresses an asset of an arbitrary length to 4 field
-///   elements: [d0, d1, d2, d3].
+/// - First the asset data, encoded as field elements (see
+///   [NonFungibleAssetDetails::data_elements()]), is hashed. This compresses an asset of an
+///   arbitrary length to 4 field elements: [d0, d1, d2, d3].
 /// - d1 is then replaced with the faucet_id which issues the asset: [d0, faucet_id, d2, d3].
 /// - Lastly, the most significant bit of d3 is set to ZERO.
 ///
@@ -211,22 +224,16 @@ impl FungibleAsset {
     // OPERATIONS
     // --------------------------------------------------------------------------------------------
 
-    /// Adds two fungible assets together and returns the result.
+    /// Returns a new asset with the specified amount added to the amount of this asset.
     ///
     /// # Errors
-    /// Returns an error if:
-    /// - The assets were not issued by the same faucet.
-    /// - The total value of assets is greater than or equal to 2^63.
-    #[allow(clippy::should_implement_trait)]
-    pub fn add(self, other: Self) -> Result<Self, AssetError> {
-        if self.faucet_id != other.faucet_id {
-            return Err(AssetError::inconsistent_faucet_ids(self.faucet_id, other.faucet_id));
-        }
-
-        let amount = self.amount.checked_add(other.amount).expect("overflow!");
-        if amount > Self::MAX_AMOUNT {
-            return Err(AssetError::amount_too_big(amount));
-        }
+    /// Returns an error if the resulting amount is greater than [FungibleAsset::MAX_AMOUNT].
+    pub fn checked_add(self, amount: u64) -> Result<Self, AssetError> {
+        let amount =
+            self.amount
+                .checked_add(amount)
+                .filter(|amount| *amount <= Self::MAX_AMOUNT)
+                .ok_or_else(|| AssetError::amount_too_big(self.amount.saturating_add(amount)))?;
 
         Ok(Self {
             faucet_id: self.faucet_id,
@@ -234,22 +241,73 @@ impl FungibleAsset {
         })
     }
 
-    /// Subtracts the specified amount from this asset and returns the resulting asset.
+    /// Returns a new asset with the specified amount subtracted from the amount of this asset.
     ///
     /// # Errors
-    /// Returns an error if this asset's amount is smaller than the requested amount.
-    pub fn sub(&mut self, amount: u64) -> Result<Self, AssetError> {
-        self.amount = self
+    /// Returns an error if the amount of this asset is smaller than the specified amount.
+    pub fn checked_sub(self, amount: u64) -> Result<Self, AssetError> {
+        let new_amount = self
             .amount
             .checked_sub(amount)
-            .ok_or(AssetError::AssetAmountNotSufficient(self.amount, amount))?;
+            .ok_or_else(|| AssetError::asset_amount_not_sufficient(self.amount, amount))?;
 
-        Ok(FungibleAsset {
+        Ok(Self {
             faucet_id: self.faucet_id,
-            amount,
+            amount: new_amount,
         })
     }
 
+    /// Merges this asset with the other asset and returns an asset holding the total amount.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The assets were not issued by the same faucet.
+    /// - The total amount of the assets is greater than [FungibleAsset::MAX_AMOUNT].
+    pub fn merge(self, other: Self) -> Result<Self, AssetError> {
+        if !self.is_from_same_faucet(&other) {
+            return Err(AssetError::inconsistent_faucet_ids(self.faucet_id, other.faucet_id));
+        }
+
+        self.checked_add(other.amount)
+    }
+
+    /// Splits the specified amount off this asset and returns a tuple of the split off asset and
+    /// the remainder of this asset.
+    ///
+    /// # Errors
+    /// Returns an error if the amount of this asset is smaller than the specified amount.
+    pub fn split(self, amount: u64) -> Result<(Self, Self), AssetError> {
+        let remainder = self.checked_sub(amount)?;
+        let split = Self {
+            faucet_id: self.faucet_id,
+            amount,
+        };
+
+        Ok((split, remainder))
+    }
+
+    /// Sums the provided assets grouped by the faucet which issued them, and returns one asset per
+    /// faucet ordered by faucet ID.
+    ///
+    /// # Errors
+    /// Returns an error if the total amount of assets issued by any faucet is greater than
+    /// [FungibleAsset::MAX_AMOUNT].
+    pub fn sum<I>(assets: I) -> Result<Vec<Self>, AssetError>
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        let mut totals = BTreeMap::<AccountId, Self>::new();
+        for asset in assets {
+            let total = match totals.get(&asset.faucet_id) {
+                Some(total) => total.merge(asset)?,
+                None => asset,
+            };
+            totals.insert(asset.faucet_id, total);
+        }
+
+        Ok(totals.into_values().collect())
+    }
+
     // HELPER FUNCTIONS
     // --------------------------------------------------------------------------------------------
 
@@ -320,9 +378,11 @@ impl TryFrom<[u8; 32]> for FungibleAsset {
     }
 }
 
+/// Displays the raw amount of the asset together with the ID of the issuing faucet. Use
+/// [TokenMetadata::format_asset()] for a representation using the token symbol and decimals.
 impl fmt::Display for FungibleAsset {
-    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        todo!()
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.amount, self.faucet_id)
     }
 }
 
@@ -348,18 +408,22 @@ impl NonFungibleAsset {
 
     /// Returns a non-fungible asset created from the specified asset details.
     ///
+    /// The asset commits to the field element encoding of the asset data (see
+    /// [NonFungibleAssetDetails::data_elements()]) rather than to its raw bytes. Assets created
+    /// from the raw bytes of the data by earlier versions have different commitments.
+    ///
     /// # Errors
     /// Returns an error if the provided faucet ID is not for a non-fungible asset faucet.
     pub fn new(details: &NonFungibleAssetDetails) -> Result<Self, AssetError> {
-        let data_hash = Hasher::hash(details.asset_data());
+        let data_hash = Hasher::hash_elements(&details.data_elements());
         Self::from_parts(details.faucet_id(), data_hash.into())
     }
 
     /// Return a non-fungible asset created from the specified faucet and using the provided
     /// hash of the asset's data.
     ///
-    /// Hash of the asset's data is expected to be computed from the binary representation of the
-    /// asset's data.
+    /// Hash of the asset's data is expected to be computed from the field element representation
+    /// of the asset's data (see [NonFungibleAssetDetails::data_elements()]).
     ///
     /// # Errors
     /// Returns an error if the provided faucet ID is not for a non-fungible asset faucet.
@@ -391,6 +455,23 @@ impl NonFungibleAsset {
         self.0
     }
 
+    /// Returns the ID of the faucet which issued this asset.
+    pub fn faucet_id(&self) -> AccountId {
+        AccountId::new_unchecked(self.0[1])
+    }
+
+    /// Verifies that the provided asset details are the preimage of this asset.
+    ///
+    /// # Errors
+    /// Returns an error if the asset computed from the provided details is not this asset.
+    pub fn verify_details(&self, details: &NonFungibleAssetDetails) -> Result<(), AssetError> {
+        if Self::new(details)? != *self {
+            return Err(AssetError::non_fungible_asset_details_mismatch(*self));
+        }
+
+        Ok(())
+    }
+
     // HELPER FUNCTIONS
     // --------------------------------------------------------------------------------------------
 
@@ -466,8 +547,12 @@ impl TryFrom<[u8; 32]> for NonFungibleAsset {
 }
 
 impl fmt::Display for NonFungibleAsset {
-    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        todo!()
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x")?;
+        for byte in self.0.into_bytes() {
+            write!(f, "{byte:02x}")?;
+        }
+        write!(f, " {}", self.faucet_id())
     }
 }
 
@@ -509,6 +594,126 @@ impl NonFungibleAssetDetails {
     pub fn asset_data(&self) -> &[u8] {
         &self.asset_data
     }
+
+    /// Returns asset data encoded as field elements.
+    ///
+    /// The first element is the length of the data in bytes, followed by the data packed into
+    /// little-endian 4-byte chunks. The result is padded with ZEROs to a multiple of 8 elements so
+    /// that it can be read into memory by the transaction kernel one double word at a time.
+    pub fn data_elements(&self) -> Vec<Felt> {
+        let mut elements = Vec::with_capacity(1 + self.asset_data.len() / 4 + 8);
+        elements.push(Felt::new(self.asset_data.len() as u64));
+        elements.extend(self.asset_data.chunks(4).map(|chunk| {
+            let mut bytes = [0_u8; 4];
+            bytes[..chunk.len()].copy_from_slice(chunk);
+            Felt::from(u32::from_le_bytes(bytes))
+        }));
+        elements.resize((elements.len() + 7) / 8 * 8, ZERO);
+        elements
+    }
+}
+
+// NON-FUNGIBLE ASSET DETAILS REGISTRY
+// ================================================================================================
+
+/// A registry of non-fungible asset details keyed by the asset they commit to.
+///
+/// The registry retains the preimage of non-fungible assets so that the underlying data can be
+/// displayed and later proven to the transaction kernel via the advice provider.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NonFungibleAssetDetailsRegistry {
+    details: BTreeMap<[u8; 32], NonFungibleAssetDetails>,
+}
+
+impl NonFungibleAssetDetailsRegistry {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new empty [NonFungibleAssetDetailsRegistry].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // ACCESSORS
+    // --------------------------------------------------------------------------------------------
+    /// Returns the details of the specified asset, or None if the asset is not in the registry.
+    pub fn get(&self, asset: &NonFungibleAsset) -> Option<&NonFungibleAssetDetails> {
+        self.details.get(&<[u8; 32]>::from(*asset))
+    }
+
+    /// Returns true if the details of the specified asset are in the registry.
+    pub fn contains(&self, asset: &NonFungibleAsset) -> bool {
+        self.details.contains_key(&<[u8; 32]>::from(*asset))
+    }
+
+    /// Returns the number of assets in the registry.
+    pub fn len(&self) -> usize {
+        self.details.len()
+    }
+
+    /// Returns true if the registry is empty.
+    pub fn is_empty(&self) -> bool {
+        self.details.is_empty()
+    }
+
+    /// Returns an iterator over the assets in the registry and their details.
+    pub fn iter(&self) -> impl Iterator<Item = (NonFungibleAsset, &NonFungibleAssetDetails)> {
+        self.details.iter().map(|(key, details)| {
+            let asset = NonFungibleAsset::try_from(*key).expect("registry key is a valid asset");
+            (asset, details)
+        })
+    }
+
+    /// Returns the advice map entries required by the transaction kernel to verify the data of the
+    /// assets in the registry.
+    ///
+    /// Each entry is keyed by the asset and holds the number of words of the asset data followed
+    /// by the asset data encoded as field elements.
+    pub fn advice_map_entries(&self) -> impl Iterator<Item = ([u8; 32], Vec<Felt>)> + '_ {
+        self.details.values().map(|details| {
+            let asset = NonFungibleAsset::new(details).expect("details were validated on insert");
+            let data = details.data_elements();
+            let mut values = Vec::with_capacity(data.len() + 1);
+            values.push(Felt::new((data.len() / 4) as u64));
+            values.extend(data);
+            (Word::from(asset).into_bytes(), values)
+        })
+    }
+
+    // MODIFIERS
+    // --------------------------------------------------------------------------------------------
+    /// Computes the asset committing to the specified details, stores the details and returns the
+    /// asset.
+    ///
+    /// # Errors
+    /// Returns an error if the faucet of the details is not a non-fungible asset faucet.
+    pub fn insert(
+        &mut self,
+        details: NonFungibleAssetDetails,
+    ) -> Result<NonFungibleAsset, AssetError> {
+        let asset = NonFungibleAsset::new(&details)?;
+        self.details.insert(asset.into(), details);
+        Ok(asset)
+    }
+
+    /// Stores the specified details for the provided asset after verifying that the details are
+    /// the preimage of the asset.
+    ///
+    /// # Errors
+    /// Returns an error if the details do not hash to the provided asset.
+    pub fn insert_for_asset(
+        &mut self,
+        asset: NonFungibleAsset,
+        details: NonFungibleAssetDetails,
+    ) -> Result<(), AssetError> {
+        asset.verify_details(&details)?;
+        self.details.insert(asset.into(), details);
+        Ok(())
+    }
+
+    /// Removes the details of the specified asset from the registry and returns them.
+    pub fn remove(&mut self, asset: &NonFungibleAsset) -> Option<NonFungibleAssetDetails> {
+        self.details.remove(&<[u8; 32]>::from(*asset))
+    }
 }
 
 // HELPER FUNCTIONS
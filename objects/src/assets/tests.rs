@@ -0,0 +1,287 @@
+use super::{
+    AccountId, Asset, AssetBundle, AssetError, Felt, FungibleAsset, NonFungibleAsset,
+    NonFungibleAssetDetails, NonFungibleAssetDetailsRegistry, TokenMetadata, ZERO,
+};
+use crate::{
+    faucets::MAX_DECIMALS,
+    mock::{
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1,
+        ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, NON_FUNGIBLE_ASSET_DATA,
+    },
+    notes::NoteVault,
+    AccountVault,
+};
+
+fn details(data: &[u8]) -> NonFungibleAssetDetails {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    NonFungibleAssetDetails::new(faucet_id, data.to_vec()).unwrap()
+}
+
+#[test]
+fn test_non_fungible_asset_data_elements() {
+    let elements = details(&[1, 2, 3, 4, 5]).data_elements();
+    assert_eq!(elements.len(), 8);
+    assert_eq!(elements[..3], [Felt::new(5), Felt::new(0x04030201), Felt::new(5)]);
+    assert!(elements[3..].iter().all(|e| *e == ZERO));
+
+    // trailing zeros produce a different encoding because the data length is committed to
+    assert_ne!(details(&[1, 2, 3]).data_elements(), details(&[1, 2, 3, 0]).data_elements());
+    assert_eq!(details(&[]).data_elements(), vec![ZERO; 8]);
+    assert_eq!(details(&[7; 29]).data_elements().len(), 16);
+}
+
+#[test]
+fn test_non_fungible_asset_display() {
+    let asset = NonFungibleAsset::new(&details(&[1, 2, 3])).unwrap();
+    let faucet_id = asset.faucet_id();
+
+    // the asset is displayed as the hex encoding of the asset word followed by the faucet ID
+    let display = asset.to_string();
+    let (word, suffix) = display.split_once(' ').unwrap();
+    assert_eq!(suffix, faucet_id.to_string());
+    assert!(word.starts_with("0x"));
+    assert_eq!(word.len(), 2 + 64);
+    assert!(word[2..].chars().all(|c| c.is_ascii_hexdigit()));
+
+    let other_asset = NonFungibleAsset::new(&details(&[4, 5, 6])).unwrap();
+    assert_ne!(other_asset.to_string(), display);
+}
+
+#[test]
+fn test_non_fungible_asset_details_registry() {
+    let mut registry = NonFungibleAssetDetailsRegistry::new();
+    let asset_details = details(&NON_FUNGIBLE_ASSET_DATA);
+    let asset = registry.insert(asset_details.clone()).unwrap();
+
+    assert_eq!(asset, NonFungibleAsset::new(&asset_details).unwrap());
+    assert_eq!(asset.faucet_id(), asset_details.faucet_id());
+    assert_eq!(registry.get(&asset), Some(&asset_details));
+    assert_eq!(registry.iter().collect::<Vec<_>>(), vec![(asset, &asset_details)]);
+
+    // the advice map value holds the number of words followed by the encoded data
+    let (key, values) = registry.advice_map_entries().next().unwrap();
+    assert_eq!(key, <[u8; 32]>::from(asset));
+    assert_eq!(values[0], Felt::new(2));
+    assert_eq!(values[1..], asset_details.data_elements());
+
+    // details which are not the preimage of the asset are rejected
+    let other_details = details(&[9, 9, 9]);
+    assert_eq!(
+        asset.verify_details(&other_details),
+        Err(AssetError::NonFungibleAssetDetailsMismatch(asset))
+    );
+    assert!(registry.insert_for_asset(asset, other_details).is_err());
+    assert_eq!(registry.get(&asset), Some(&asset_details));
+
+    assert_eq!(registry.remove(&asset), Some(asset_details));
+    assert!(registry.is_empty());
+}
+
+#[test]
+fn test_token_metadata_format() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let metadata = TokenMetadata::new(faucet_id, "POL", 2).unwrap();
+
+    assert_eq!(metadata.format_amount(1250), "12.5");
+    assert_eq!(metadata.format_amount(1205), "12.05");
+    assert_eq!(metadata.format_amount(1200), "12");
+    assert_eq!(metadata.format_amount(5), "0.05");
+    assert_eq!(TokenMetadata::new(faucet_id, "POL", 0).unwrap().format_amount(1250), "1250");
+
+    let asset = FungibleAsset::new(faucet_id, 1250).unwrap();
+    assert_eq!(metadata.format_asset(&asset).unwrap(), "12.5 POL");
+    assert_eq!(asset.to_string(), format!("1250 {faucet_id}"));
+
+    let other_faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1).unwrap();
+    let other_asset = FungibleAsset::new(other_faucet_id, 1250).unwrap();
+    assert!(metadata.format_asset(&other_asset).is_err());
+}
+
+#[test]
+fn test_token_metadata_parse() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let metadata = TokenMetadata::new(faucet_id, "POL", 2).unwrap();
+
+    assert_eq!(metadata.parse_asset("12.5 POL"), FungibleAsset::new(faucet_id, 1250));
+    assert_eq!(metadata.parse_asset(" 12 POL "), FungibleAsset::new(faucet_id, 1200));
+    assert_eq!(metadata.parse_amount("0.01"), Ok(1));
+
+    for amount in ["", ".5", "12.", "1.234", "-1", "1e3", "1.2.3"] {
+        assert_eq!(
+            metadata.parse_amount(amount),
+            Err(AssetError::InvalidTokenAmount(amount.into()))
+        );
+    }
+    assert!(metadata.parse_asset("12.5").is_err());
+    assert!(metadata.parse_asset("12.5 POL POL").is_err());
+    assert_eq!(
+        metadata.parse_asset("12.5 ETH"),
+        Err(AssetError::TokenSymbolMismatch("POL".into(), "ETH".into()))
+    );
+
+    // amounts above the maximum are rejected whether or not they overflow a u64
+    let max_amount = metadata.format_amount(FungibleAsset::MAX_AMOUNT);
+    assert_eq!(metadata.parse_amount(&max_amount), Ok(FungibleAsset::MAX_AMOUNT));
+    assert_eq!(
+        metadata.parse_amount("92233720368547758.08"),
+        Err(AssetError::AmountTooBig(FungibleAsset::MAX_AMOUNT + 1))
+    );
+    assert!(metadata.parse_amount("184467440737095516.16").is_err());
+}
+
+#[test]
+fn test_token_metadata_validation() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    assert_eq!(
+        TokenMetadata::new(faucet_id, "pol", 2),
+        Err(AssetError::InvalidTokenSymbol("pol".into()))
+    );
+    assert_eq!(
+        TokenMetadata::new(faucet_id, "POL", MAX_DECIMALS + 1),
+        Err(AssetError::InvalidTokenDecimals(MAX_DECIMALS + 1))
+    );
+
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    assert!(TokenMetadata::new(faucet_id, "POL", 2).is_err());
+}
+
+#[test]
+fn test_token_symbol_encoding() {
+    use super::token::{decode_token_symbol, encode_token_symbol};
+
+    assert_eq!(encode_token_symbol("A").unwrap(), Felt::new(1));
+    assert_eq!(encode_token_symbol("AB").unwrap(), Felt::new(1 + 2 * 27));
+    assert_ne!(encode_token_symbol("AB").unwrap(), encode_token_symbol("BA").unwrap());
+
+    assert!(encode_token_symbol("").is_err());
+    assert!(encode_token_symbol("ABCDEFG").is_err());
+    assert!(encode_token_symbol("pol").is_err());
+    assert!(encode_token_symbol("PO1").is_err());
+
+    for symbol in ["A", "POL", "ZZZZZZ"] {
+        assert_eq!(decode_token_symbol(encode_token_symbol(symbol).unwrap()), Ok(symbol.into()));
+    }
+
+    // zero digits and symbols longer than the maximum length are not valid encodings
+    assert!(decode_token_symbol(ZERO).is_err());
+    assert!(decode_token_symbol(Felt::new(27)).is_err());
+    assert!(decode_token_symbol(Felt::new(27_u64.pow(6))).is_err());
+}
+
+#[test]
+fn test_token_metadata_from_faucet_metadata() {
+    use super::token::encode_token_symbol;
+
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let symbol = encode_token_symbol("POL").unwrap();
+    let metadata = [Felt::new(1000), Felt::new(2), symbol, ZERO];
+    assert_eq!(
+        TokenMetadata::from_faucet_metadata(faucet_id, metadata),
+        TokenMetadata::new(faucet_id, "POL", 2)
+    );
+
+    let metadata = [Felt::new(1000), Felt::new(256), symbol, ZERO];
+    assert_eq!(
+        TokenMetadata::from_faucet_metadata(faucet_id, metadata),
+        Err(AssetError::InvalidTokenDecimals(u8::MAX))
+    );
+
+    let metadata = [Felt::new(1000), Felt::new(2), ZERO, ZERO];
+    assert_eq!(
+        TokenMetadata::from_faucet_metadata(faucet_id, metadata),
+        Err(AssetError::InvalidTokenSymbol("0".into()))
+    );
+}
+
+#[test]
+fn test_fungible_asset_arithmetic() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let asset = FungibleAsset::new(faucet_id, 100).unwrap();
+
+    assert_eq!(asset.checked_add(50), FungibleAsset::new(faucet_id, 150));
+    assert_eq!(asset.checked_sub(40), FungibleAsset::new(faucet_id, 60));
+    assert_eq!(asset.checked_sub(101), Err(AssetError::AssetAmountNotSufficient(100, 101)));
+    assert_eq!(
+        asset.checked_add(FungibleAsset::MAX_AMOUNT),
+        Err(AssetError::AmountTooBig(FungibleAsset::MAX_AMOUNT + 100))
+    );
+    assert_eq!(asset.checked_add(u64::MAX), Err(AssetError::AmountTooBig(u64::MAX)));
+
+    let (split, remainder) = asset.split(30).unwrap();
+    assert_eq!((split.amount(), remainder.amount()), (30, 70));
+    assert_eq!(split.merge(remainder), Ok(asset));
+    assert!(asset.split(101).is_err());
+
+    let other_faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1).unwrap();
+    let other_asset = FungibleAsset::new(other_faucet_id, 100).unwrap();
+    assert_eq!(
+        asset.merge(other_asset),
+        Err(AssetError::InconsistentFaucetIds(faucet_id, other_faucet_id))
+    );
+}
+
+#[test]
+fn test_fungible_asset_sum() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let other_faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1).unwrap();
+    let asset = |faucet_id, amount| FungibleAsset::new(faucet_id, amount).unwrap();
+
+    let mut expected = vec![asset(faucet_id, 30), asset(other_faucet_id, 5)];
+    expected.sort_by_key(|asset| asset.faucet_id());
+    let assets = [asset(faucet_id, 10), asset(other_faucet_id, 5), asset(faucet_id, 20)];
+    assert_eq!(FungibleAsset::sum(assets), Ok(expected));
+    assert_eq!(FungibleAsset::sum([]), Ok(vec![]));
+
+    let assets = [asset(faucet_id, FungibleAsset::MAX_AMOUNT), asset(faucet_id, 1)];
+    assert!(FungibleAsset::sum(assets).is_err());
+}
+
+#[test]
+fn test_asset_bundle() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let other_faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1).unwrap();
+    let fungible = |faucet_id, amount| Asset::from(FungibleAsset::new(faucet_id, amount).unwrap());
+    let non_fungible = |data: &[u8]| Asset::from(NonFungibleAsset::new(&details(data)).unwrap());
+
+    // fungible assets issued by the same faucet are merged
+    let bundle = AssetBundle::from_assets([
+        fungible(faucet_id, 10),
+        non_fungible(&[1, 2, 3]),
+        fungible(other_faucet_id, 5),
+        fungible(faucet_id, 20),
+    ])
+    .unwrap();
+    assert_eq!(bundle.num_assets(), 3);
+    assert_eq!(bundle.get_balance(faucet_id), 30);
+    assert_eq!(bundle.get_balance(other_faucet_id), 5);
+
+    // non-fungible assets are kept unique
+    let duplicate = AssetBundle::from_assets([non_fungible(&[1, 2, 3]), non_fungible(&[1, 2, 3])]);
+    assert!(matches!(duplicate, Err(AssetError::DuplicateNonFungibleAsset(_))));
+
+    // containment and subtraction
+    let other =
+        AssetBundle::from_assets([fungible(faucet_id, 25), non_fungible(&[1, 2, 3])]).unwrap();
+    assert!(bundle.contains(&other));
+    assert!(!other.contains(&bundle));
+    assert!(bundle.contains(&AssetBundle::new()));
+
+    let remainder = bundle.checked_sub(&other).unwrap();
+    assert_eq!(remainder.num_assets(), 2);
+    assert_eq!(remainder.get_balance(faucet_id), 5);
+    assert!(!remainder.contains_asset(&non_fungible(&[1, 2, 3])));
+    assert!(remainder.checked_sub(&other).is_err());
+    assert!(bundle.checked_sub(&bundle).unwrap().is_empty());
+
+    let mut merged = remainder.clone();
+    merged.merge(&other).unwrap();
+    assert_eq!(merged, bundle);
+
+    // conversion into vaults
+    let assets: Vec<Asset> = bundle.clone().into();
+    let account_vault = AccountVault::try_from(bundle.clone()).unwrap();
+    assert_eq!(account_vault, AccountVault::new(&assets).unwrap());
+    let note_vault = NoteVault::try_from(bundle).unwrap();
+    assert_eq!(note_vault, NoteVault::new(&assets).unwrap());
+    assert!(NoteVault::try_from(AssetBundle::new()).is_err());
+}
@@ -0,0 +1,249 @@
+use super::{
+    AccountId, AccountType, AssetError, Felt, FungibleAsset, StarkField, String, ToString, Word,
+};
+use crate::{
+    faucets::{FAUCET_METADATA_SLOT, MAX_DECIMALS, MAX_TOKEN_SYMBOL_LENGTH},
+    Account,
+};
+
+// TOKEN METADATA
+// ================================================================================================
+
+/// Metadata of a fungible token issued by a fungible faucet.
+///
+/// The metadata consists of the token symbol and the number of decimals of the token. It is used
+/// to convert asset amounts, which are always expressed in the smallest unit of the token, to and
+/// from their human-readable representation. For example, with 2 decimals an asset amount of 1250
+/// is displayed as "12.5 POL".
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TokenMetadata {
+    faucet_id: AccountId,
+    symbol: String,
+    decimals: u8,
+}
+
+impl TokenMetadata {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Returns token metadata for the fungible asset issued by the specified faucet.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The faucet_id is not a valid fungible faucet ID.
+    /// - The symbol is empty, longer than [MAX_TOKEN_SYMBOL_LENGTH] characters, or contains
+    ///   characters other than uppercase ASCII letters.
+    /// - The number of decimals is greater than [MAX_DECIMALS].
+    pub fn new(faucet_id: AccountId, symbol: &str, decimals: u8) -> Result<Self, AssetError> {
+        if !matches!(faucet_id.account_type(), AccountType::FungibleFaucet) {
+            return Err(AssetError::not_a_fungible_faucet_id(faucet_id));
+        }
+        encode_token_symbol(symbol)?;
+        validate_token_decimals(decimals)?;
+
+        Ok(Self {
+            faucet_id,
+            symbol: symbol.to_string(),
+            decimals,
+        })
+    }
+
+    /// Returns token metadata of the specified fungible faucet read from its
+    /// [FAUCET_METADATA_SLOT].
+    ///
+    /// # Errors
+    /// Returns an error if the account is not a fungible faucet or if the metadata stored in the
+    /// faucet is invalid as described in [TokenMetadata::from_faucet_metadata()].
+    pub fn from_faucet(faucet: &Account) -> Result<Self, AssetError> {
+        let metadata = faucet.storage().get_item(FAUCET_METADATA_SLOT);
+        Self::from_faucet_metadata(faucet.id(), metadata.into())
+    }
+
+    /// Returns token metadata of the specified faucet decoded from the value of the faucet's
+    /// [FAUCET_METADATA_SLOT], which is laid out as `[max_supply, decimals, token_symbol, 0]`.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The faucet_id is not a valid fungible faucet ID.
+    /// - The token symbol is not a valid encoding of a token symbol.
+    /// - The number of decimals is greater than [MAX_DECIMALS].
+    pub fn from_faucet_metadata(faucet_id: AccountId, metadata: Word) -> Result<Self, AssetError> {
+        // values which do not fit into a u8 are above the maximum number of decimals as well
+        let decimals = u8::try_from(metadata[1].as_int()).unwrap_or(u8::MAX);
+        let symbol = decode_token_symbol(metadata[2])?;
+        Self::new(faucet_id, &symbol, decimals)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns ID of the faucet which issues the token.
+    pub fn faucet_id(&self) -> AccountId {
+        self.faucet_id
+    }
+
+    /// Returns the symbol of the token.
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    /// Returns the number of decimals of the token.
+    pub fn decimals(&self) -> u8 {
+        self.decimals
+    }
+
+    // FORMATTING
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the human-readable representation of the specified amount, e.g. "12.5" for an
+    /// amount of 1250 and 2 decimals. Trailing zeros of the fractional part are omitted.
+    pub fn format_amount(&self, amount: u64) -> String {
+        let unit = self.unit();
+        let (integer, fraction) = (amount / unit, amount % unit);
+        if fraction == 0 {
+            return integer.to_string();
+        }
+
+        let fraction = format!("{fraction:0width$}", width = self.decimals as usize);
+        format!("{integer}.{}", fraction.trim_end_matches('0'))
+    }
+
+    /// Returns the human-readable representation of the specified asset, e.g. "12.5 POL".
+    ///
+    /// # Errors
+    /// Returns an error if the asset was not issued by the faucet of this token.
+    pub fn format_asset(&self, asset: &FungibleAsset) -> Result<String, AssetError> {
+        if asset.faucet_id() != self.faucet_id {
+            return Err(AssetError::inconsistent_faucet_ids(self.faucet_id, asset.faucet_id()));
+        }
+
+        Ok(format!("{} {}", self.format_amount(asset.amount()), self.symbol))
+    }
+
+    // PARSING
+    // --------------------------------------------------------------------------------------------
+
+    /// Parses a human-readable amount, e.g. "12.5", into the amount in the smallest unit of the
+    /// token.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The amount is not a decimal number or has more fractional digits than the token has
+    ///   decimals.
+    /// - The amount is greater than [FungibleAsset::MAX_AMOUNT].
+    pub fn parse_amount(&self, amount: &str) -> Result<u64, AssetError> {
+        let invalid_amount = || AssetError::invalid_token_amount(amount.to_string());
+
+        let (integer, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+        if integer.is_empty()
+            || amount.ends_with('.')
+            || fraction.len() > self.decimals as usize
+            || !integer.bytes().chain(fraction.bytes()).all(|c| c.is_ascii_digit())
+        {
+            return Err(invalid_amount());
+        }
+
+        let integer = integer.parse::<u64>().map_err(|_| invalid_amount())?;
+        let fraction = fraction.bytes().fold(0_u64, |acc, c| acc * 10 + (c - b'0') as u64)
+            * 10_u64.pow((self.decimals as usize - fraction.len()) as u32);
+
+        let amount = integer
+            .checked_mul(self.unit())
+            .and_then(|integer| integer.checked_add(fraction))
+            .ok_or_else(invalid_amount)?;
+        if amount > FungibleAsset::MAX_AMOUNT {
+            return Err(AssetError::amount_too_big(amount));
+        }
+
+        Ok(amount)
+    }
+
+    /// Parses a human-readable asset, e.g. "12.5 POL", into a fungible asset issued by the faucet
+    /// of this token.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The string is not an amount followed by a token symbol separated by whitespace.
+    /// - The token symbol is not the symbol of this token.
+    /// - The amount is invalid as described in [TokenMetadata::parse_amount()].
+    pub fn parse_asset(&self, asset: &str) -> Result<FungibleAsset, AssetError> {
+        let mut parts = asset.split_whitespace();
+        let (amount, symbol) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(amount), Some(symbol), None) => (amount, symbol),
+            _ => return Err(AssetError::invalid_token_amount(asset.to_string())),
+        };
+        if symbol != self.symbol {
+            return Err(AssetError::token_symbol_mismatch(self.symbol.clone(), symbol.to_string()));
+        }
+
+        FungibleAsset::new(self.faucet_id, self.parse_amount(amount)?)
+    }
+
+    // HELPER FUNCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of the smallest units of the token in one whole token.
+    fn unit(&self) -> u64 {
+        10_u64.pow(self.decimals as u32)
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Encodes the token symbol into a single field element.
+///
+/// Each character is mapped to a value in the range [1, 26], and the symbol is encoded as a base
+/// 27 number with the first character being the least significant digit.
+///
+/// # Errors
+/// Returns an error if the symbol is empty, longer than [MAX_TOKEN_SYMBOL_LENGTH] characters, or
+/// contains characters other than uppercase ASCII letters.
+pub(crate) fn encode_token_symbol(symbol: &str) -> Result<Felt, AssetError> {
+    if symbol.is_empty()
+        || symbol.len() > MAX_TOKEN_SYMBOL_LENGTH
+        || !symbol.bytes().all(|c| c.is_ascii_uppercase())
+    {
+        return Err(AssetError::invalid_token_symbol(symbol.to_string()));
+    }
+
+    let encoded = symbol.bytes().rev().fold(0_u64, |acc, c| acc * 27 + (c - b'A' + 1) as u64);
+
+    Ok(Felt::new(encoded))
+}
+
+/// Decodes a token symbol encoded by [encode_token_symbol()].
+///
+/// # Errors
+/// Returns an error if the field element is not a valid encoding of a token symbol.
+pub(crate) fn decode_token_symbol(encoded: Felt) -> Result<String, AssetError> {
+    let invalid_symbol = || AssetError::invalid_token_symbol(encoded.as_int().to_string());
+
+    let mut remainder = encoded.as_int();
+    let mut symbol = String::new();
+    while remainder > 0 {
+        let digit = (remainder % 27) as u8;
+        if digit == 0 || symbol.len() == MAX_TOKEN_SYMBOL_LENGTH {
+            return Err(invalid_symbol());
+        }
+        symbol.push((b'A' + digit - 1) as char);
+        remainder /= 27;
+    }
+    if symbol.is_empty() {
+        return Err(invalid_symbol());
+    }
+
+    Ok(symbol)
+}
+
+/// Validates the number of decimals of a fungible token.
+///
+/// # Errors
+/// Returns an error if the number of decimals is greater than [MAX_DECIMALS].
+pub(crate) fn validate_token_decimals(decimals: u8) -> Result<(), AssetError> {
+    if decimals > MAX_DECIMALS {
+        return Err(AssetError::invalid_token_decimals(decimals));
+    }
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use super::{
     assets::{Asset, FungibleAsset, NonFungibleAsset},
-    AccountId, Digest, MerkleError, String, Word,
+    AccountId, AccountType, Digest, MerkleError, String, Word,
 };
 use assembly::{AssemblyError, ParsingError};
 use core::fmt;
@@ -10,11 +10,17 @@ use core::fmt;
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum AccountError {
+    AccountIdBech32InvalidChecksum,
+    AccountIdBech32Malformed(String),
+    AccountIdBech32UnexpectedNetwork(String),
     AccountIdInvalidFieldElement(String),
+    AccountIdInvalidStorageMode,
+    AccountIdSeedMismatch(Word),
     AccountIdTooFewOnes,
     AddFungibleAssetBalanceError(AssetError),
     ApplyStorageSlotsDiffFailed(MerkleError),
     ApplyStorageStoreDiffFailed(MerkleError),
+    ApplyStorageMapDiffFailed(u8),
     SubtractFungibleAssetBalanceError(AssetError),
     DuplicateNonFungibleAsset(NonFungibleAsset),
     NonFungibleAssetNotFound(NonFungibleAsset),
@@ -24,11 +30,23 @@ pub enum AccountError {
     SetStoreNodeFailed(MerkleError),
     CodeParsingFailed(ParsingError),
     AccountCodeAssemblerError(AssemblyError),
+    AccountCodeProcedureNotFound(String),
+    AccountCodeProcedureRootsMismatch(usize, usize),
+    AccountCodeTooManyProcedures(usize, usize),
+    AccountCodeUpdatesNotSupported(AccountType),
     FungibleFaucetIdInvalidFirstBit,
+    FungibleFaucetInvalidMetadata(AssetError),
+    FungibleFaucetMaxSupplyTooLarge(u64),
     NotAFungibleFaucetId(AccountId),
     NotANonFungibleAsset(Asset),
     DuplicateStorageItems(MerkleError),
+    DuplicateStorageMapKeys(MerkleError),
     DuplicateAsset(MerkleError),
+    StorageMapDataMissing(u8),
+    StorageSlotIsReserved(u8),
+    StorageSlotNotArray(u8),
+    StorageSlotNotMap(u8),
+    StorageSlotNotValue(u8),
     NonceMustBeMonotonicallyIncreasing(u64, u64),
     InconsistentAccountIdSeed {
         expected: AccountId,
@@ -84,17 +102,24 @@ impl std::error::Error for AccountError {}
 pub enum AssetError {
     AmountTooBig(u64),
     AssetAmountNotSufficient(u64, u64),
+    DuplicateNonFungibleAsset(NonFungibleAsset),
     FungibleAssetInvalidFirstBit,
     FungibleAssetInvalidTag(u32),
     FungibleAssetInvalidWord(Word),
     InconsistentFaucetIds(AccountId, AccountId),
     InvalidAccountId(String),
     InvalidFieldElement(String),
+    InvalidTokenAmount(String),
+    InvalidTokenDecimals(u8),
+    InvalidTokenSymbol(String),
+    NonFungibleAssetDetailsMismatch(NonFungibleAsset),
     NonFungibleAssetInvalidFirstBit,
     NonFungibleAssetInvalidTag(u32),
+    NonFungibleAssetNotFound(NonFungibleAsset),
     NotAFungibleFaucetId(AccountId),
     NotANonFungibleFaucetId(AccountId),
     NotAnAsset(Word),
+    TokenSymbolMismatch(String, String),
 }
 
 impl AssetError {
@@ -106,6 +131,10 @@ impl AssetError {
         Self::AssetAmountNotSufficient(available, requested)
     }
 
+    pub fn duplicate_non_fungible_asset(asset: NonFungibleAsset) -> Self {
+        Self::DuplicateNonFungibleAsset(asset)
+    }
+
     pub fn fungible_asset_invalid_first_bit() -> Self {
         Self::FungibleAssetInvalidFirstBit
     }
@@ -130,6 +159,22 @@ impl AssetError {
         Self::InvalidFieldElement(msg)
     }
 
+    pub fn invalid_token_amount(amount: String) -> Self {
+        Self::InvalidTokenAmount(amount)
+    }
+
+    pub fn invalid_token_decimals(decimals: u8) -> Self {
+        Self::InvalidTokenDecimals(decimals)
+    }
+
+    pub fn invalid_token_symbol(symbol: String) -> Self {
+        Self::InvalidTokenSymbol(symbol)
+    }
+
+    pub fn non_fungible_asset_details_mismatch(asset: NonFungibleAsset) -> Self {
+        Self::NonFungibleAssetDetailsMismatch(asset)
+    }
+
     pub fn non_fungible_asset_invalid_first_bit() -> Self {
         Self::NonFungibleAssetInvalidFirstBit
     }
@@ -138,6 +183,10 @@ impl AssetError {
         Self::NonFungibleAssetInvalidTag(tag)
     }
 
+    pub fn non_fungible_asset_not_found(asset: NonFungibleAsset) -> Self {
+        Self::NonFungibleAssetNotFound(asset)
+    }
+
     pub fn not_a_fungible_faucet_id(id: AccountId) -> Self {
         Self::NotAFungibleFaucetId(id)
     }
@@ -149,6 +198,10 @@ impl AssetError {
     pub fn not_an_asset(value: Word) -> Self {
         Self::NotAnAsset(value)
     }
+
+    pub fn token_symbol_mismatch(expected: String, actual: String) -> Self {
+        Self::TokenSymbolMismatch(expected, actual)
+    }
 }
 
 impl fmt::Display for AssetError {
@@ -176,6 +229,8 @@ pub enum NoteError {
     InvalidVaultDataLen(usize),
     InvalidVaultAssetData(AssetError),
     NoteMetadataSenderInvalid(AccountError),
+    NoteTagInvalid(u64),
+    NoteTagUseCaseIdTooLarge(u16),
     ScriptCompilationError(AssemblyError),
     TooManyAssets(usize),
     TooManyInputs(usize),
@@ -254,6 +309,7 @@ impl std::error::Error for ExecutedTransactionError {}
 // ================================================================================================
 #[derive(Debug)]
 pub enum TransactionResultError {
+    AccountIdInvalid(AccountError),
     CreatedNoteDataNotFound,
     CreatedNoteDataInvalid(NoteError),
     CreatedNotesCommitmentInconsistent(Digest, Digest),
@@ -262,7 +318,12 @@ pub enum TransactionResultError {
     InconsistentAccountCodeHash(Digest, Digest),
     ExtractAccountStorageSlotsDeltaFailed(MerkleError),
     ExtractAccountStorageStoreDeltaFailed(MerkleError),
+    ExtractTieredSmtLeavesFailed(MerkleError),
+    TieredSmtLeafDataInvalid(Digest),
+    TieredSmtLeafDataNotFound(Digest),
+    NewAccountSeedNotProvided,
     UpdatedAccountCodeInvalid(AccountError),
+    UpdatedAccountCodeNotProvided(Digest),
 }
 
 impl fmt::Display for TransactionResultError {
@@ -0,0 +1,122 @@
+use super::{
+    assets::{encode_token_symbol, validate_token_decimals, FungibleAsset},
+    wallets::BASIC_WALLET_PUBLIC_KEY_SLOT,
+    Account, AccountBuilder, AccountError, AccountStorageMode, AccountType, Assembler, Felt,
+    ModuleAst, StorageItem, Word, ZERO,
+};
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Index of the account storage slot at which the faucet metadata is stored.
+///
+/// The metadata is stored as `[max_supply, decimals, token_symbol, 0]`.
+pub const FAUCET_METADATA_SLOT: u8 = 1;
+
+/// The maximum number of decimals supported by fungible faucets.
+pub const MAX_DECIMALS: u8 = 12;
+
+/// The maximum number of characters in a token symbol.
+pub const MAX_TOKEN_SYMBOL_LENGTH: usize = 6;
+
+/// Source code of the account module which exposes the `miden::faucets::basic_fungible`
+/// procedures together with the authentication procedure of the basic wallet.
+pub(crate) const BASIC_FUNGIBLE_FAUCET_CODE: &str = "
+    use.miden::faucets::basic_fungible->basic_faucet
+    use.miden::wallets::basic->basic_wallet
+
+    export.distribute
+        exec.basic_faucet::distribute
+    end
+
+    export.burn
+        exec.basic_faucet::burn
+    end
+
+    export.auth_tx
+        exec.basic_wallet::auth_tx
+    end
+";
+
+// BASIC FUNGIBLE FAUCET
+// ================================================================================================
+
+/// Returns a new basic fungible faucet account together with the seed from which its ID was
+/// derived.
+///
+/// The faucet issues a fungible asset with the specified token symbol and number of decimals. The
+/// total issuance of the asset is capped at `max_supply`. The account code exposes `distribute`
+/// and `burn` procedures from the `miden::faucets::basic_fungible` module, and the `auth_tx`
+/// procedure of the basic wallet which authenticates the transaction using the specified public
+/// key.
+///
+/// The account storage is laid out as follows:
+/// - [BASIC_WALLET_PUBLIC_KEY_SLOT]: the public key of the faucet owner, which is read by the
+///   `auth_tx` procedure of the basic wallet.
+/// - [FAUCET_METADATA_SLOT]: `[max_supply, decimals, token_symbol, 0]`, which can be read back
+///   via [crate::assets::TokenMetadata::from_faucet()].
+/// - [crate::accounts::AccountStorage::FAUCET_ISSUANCE_SLOT]: the total issuance, which is
+///   maintained by the transaction kernel.
+///
+/// The provided assembler must be instantiated with the Miden library, the standard library and
+/// the transaction kernel.
+///
+/// # Errors
+/// Returns an error if:
+/// - The token symbol is empty, longer than [MAX_TOKEN_SYMBOL_LENGTH] characters, or contains
+///   characters other than uppercase ASCII letters.
+/// - The number of decimals is greater than [MAX_DECIMALS].
+/// - The max supply is greater than [FungibleAsset::MAX_AMOUNT].
+/// - The account code could not be compiled or a valid account seed could not be found.
+pub fn create_basic_fungible_faucet(
+    init_seed: [u8; 32],
+    token_symbol: &str,
+    decimals: u8,
+    max_supply: u64,
+    pub_key: Word,
+    storage_mode: AccountStorageMode,
+    assembler: &Assembler,
+) -> Result<(Account, Word), AccountError> {
+    basic_fungible_faucet_builder(
+        init_seed,
+        token_symbol,
+        decimals,
+        max_supply,
+        pub_key,
+        storage_mode,
+    )?
+    .build(assembler)
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns the builder of a basic fungible faucet after validating the metadata of the asset it
+/// issues.
+fn basic_fungible_faucet_builder(
+    init_seed: [u8; 32],
+    token_symbol: &str,
+    decimals: u8,
+    max_supply: u64,
+    pub_key: Word,
+    storage_mode: AccountStorageMode,
+) -> Result<AccountBuilder, AccountError> {
+    let token_symbol =
+        encode_token_symbol(token_symbol).map_err(AccountError::FungibleFaucetInvalidMetadata)?;
+    validate_token_decimals(decimals).map_err(AccountError::FungibleFaucetInvalidMetadata)?;
+    if max_supply > FungibleAsset::MAX_AMOUNT {
+        return Err(AccountError::FungibleFaucetMaxSupplyTooLarge(max_supply));
+    }
+
+    Ok(AccountBuilder::new(init_seed, ModuleAst::parse(BASIC_FUNGIBLE_FAUCET_CODE)?)
+        .account_type(AccountType::FungibleFaucet)
+        .storage_mode(storage_mode)
+        .storage_item(StorageItem::new_value(BASIC_WALLET_PUBLIC_KEY_SLOT, pub_key))
+        .storage_item(StorageItem::new_value(
+            FAUCET_METADATA_SLOT,
+            [Felt::new(max_supply), Felt::from(decimals), token_symbol, ZERO],
+        )))
+}
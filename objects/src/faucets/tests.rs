@@ -0,0 +1,120 @@
+use super::{
+    basic_fungible_faucet_builder, create_basic_fungible_faucet, AccountError, AccountStorageMode,
+    AccountType, Felt, FungibleAsset, BASIC_WALLET_PUBLIC_KEY_SLOT, FAUCET_METADATA_SLOT,
+    MAX_DECIMALS, ZERO,
+};
+use crate::{
+    assets::{encode_token_symbol, AssetError, TokenMetadata},
+    mock::{assembler, mock_basic_fungible_faucet, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN},
+    validate_account_seed, AccountId,
+};
+use crypto::Word;
+
+const PUB_KEY: Word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+
+#[test]
+fn test_basic_fungible_faucet_builder() {
+    let mut assembler = assembler();
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+
+    // the faucet is built with a fixed ID, so that no account seed needs to be ground
+    let faucet =
+        basic_fungible_faucet_builder([7; 32], "POL", 8, 1000, PUB_KEY, AccountStorageMode::Public)
+            .unwrap()
+            .build_with_id(faucet_id, &assembler)
+            .unwrap();
+
+    assert_eq!(faucet.code().procedures().len(), 3);
+    assert_eq!(Word::from(faucet.storage().get_item(BASIC_WALLET_PUBLIC_KEY_SLOT)), PUB_KEY);
+
+    // the metadata slot is laid out as [max_supply, decimals, token_symbol, 0]
+    let token_symbol = encode_token_symbol("POL").unwrap();
+    assert_eq!(
+        Word::from(faucet.storage().get_item(FAUCET_METADATA_SLOT)),
+        [Felt::new(1000), Felt::new(8), token_symbol, ZERO]
+    );
+
+    let metadata = TokenMetadata::from_faucet(&faucet).unwrap();
+    assert_eq!(metadata.faucet_id(), faucet_id);
+    assert_eq!(metadata.symbol(), "POL");
+    assert_eq!(metadata.decimals(), 8);
+
+    // the faucet has the code of the mock basic fungible faucet used to test transactions
+    let mock_faucet = mock_basic_fungible_faucet(PUB_KEY, 1000, 0, &mut assembler);
+    assert_eq!(faucet.code().root(), mock_faucet.code().root());
+}
+
+#[test]
+#[ignore = "grinding a faucet account seed requires computing ~2^31 hashes"]
+fn test_create_basic_fungible_faucet() {
+    let assembler = assembler();
+
+    let (faucet, seed) = create_basic_fungible_faucet(
+        [7; 32],
+        "POL",
+        8,
+        1000,
+        PUB_KEY,
+        AccountStorageMode::Public,
+        &assembler,
+    )
+    .unwrap();
+
+    assert_eq!(faucet.id().account_type(), AccountType::FungibleFaucet);
+    assert!(validate_account_seed(&faucet, seed).is_ok());
+    assert_eq!(Word::from(faucet.storage().get_item(BASIC_WALLET_PUBLIC_KEY_SLOT)), PUB_KEY);
+
+    let metadata = TokenMetadata::from_faucet(&faucet).unwrap();
+    assert_eq!(metadata.faucet_id(), faucet.id());
+    assert_eq!(metadata.symbol(), "POL");
+    assert_eq!(metadata.decimals(), 8);
+}
+
+#[test]
+fn test_create_basic_fungible_faucet_invalid_metadata() {
+    let assembler = assembler();
+
+    let result = create_basic_fungible_faucet(
+        [0; 32],
+        "POL",
+        MAX_DECIMALS + 1,
+        1000,
+        PUB_KEY,
+        AccountStorageMode::Public,
+        &assembler,
+    );
+    assert_eq!(
+        result,
+        Err(AccountError::FungibleFaucetInvalidMetadata(AssetError::InvalidTokenDecimals(
+            MAX_DECIMALS + 1
+        )))
+    );
+
+    let max_supply = FungibleAsset::MAX_AMOUNT + 1;
+    let result = create_basic_fungible_faucet(
+        [0; 32],
+        "POL",
+        8,
+        max_supply,
+        PUB_KEY,
+        AccountStorageMode::Public,
+        &assembler,
+    );
+    assert_eq!(result, Err(AccountError::FungibleFaucetMaxSupplyTooLarge(max_supply)));
+
+    let result = create_basic_fungible_faucet(
+        [0; 32],
+        "pol",
+        8,
+        1000,
+        PUB_KEY,
+        AccountStorageMode::Public,
+        &assembler,
+    );
+    assert_eq!(
+        result,
+        Err(AccountError::FungibleFaucetInvalidMetadata(AssetError::InvalidTokenSymbol(
+            "pol".into()
+        )))
+    );
+}
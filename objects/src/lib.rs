@@ -22,9 +22,12 @@ use miden_processor::{AdviceInputs, StackOutputs};
 
 mod accounts;
 pub use accounts::{
-    validate_account_seed, Account, AccountCode, AccountDelta, AccountId, AccountStorage,
-    AccountStub, AccountType, AccountVault, StorageItem,
+    validate_account_seed, Account, AccountBuilder, AccountCode, AccountDelta, AccountId,
+    AccountStorage, AccountStorageMode, AccountStub, AccountType, AccountVault, AccountVaultDelta,
+    NetworkId, ProcedureMetadata, StorageItem, StorageMap, StorageSlotType,
 };
+#[cfg(feature = "std")]
+pub use accounts::{AccountSeedGrinder, CancellationToken, SeedGrindingOutcome};
 
 mod advice;
 use advice::{AdviceInputsBuilder, ToAdviceInputs};
@@ -38,6 +41,8 @@ pub use block::BlockHeader;
 pub mod chain;
 pub use chain::ChainMmr;
 
+pub mod faucets;
+
 mod errors;
 pub use errors::{
     AccountError, AssetError, ExecutedTransactionError, NoteError, PreparedTransactionError,
@@ -49,5 +54,7 @@ pub use result::TryFromVmResult;
 
 pub mod transaction;
 
+pub mod wallets;
+
 #[cfg(any(test, feature = "testing"))]
 pub mod mock;
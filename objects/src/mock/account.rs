@@ -1,11 +1,18 @@
 use super::super::{
-    assets::{Asset, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
-    Account, AccountCode, AccountId, AccountStorage, AccountVault, Felt, Vec, Word,
+    assets::{
+        encode_token_symbol, Asset, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails,
+    },
+    faucets::{BASIC_FUNGIBLE_FAUCET_CODE, FAUCET_METADATA_SLOT},
+    wallets::{BASIC_WALLET_CODE, BASIC_WALLET_PUBLIC_KEY_SLOT},
+    Account, AccountCode, AccountId, AccountStorage, AccountVault, Felt, StorageItem, StorageMap,
+    Vec, Word,
 };
 use super::{
     ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+    ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+    ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
     ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
-    ACCOUNT_SEED_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, CHILD_ROOT_PARENT_LEAF_INDEX,
+    ACCOUNT_SEED_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN, CHILD_ROOT_PARENT_LEAF_INDEX,
     CHILD_SMT_DEPTH, CHILD_STORAGE_INDEX_0, CHILD_STORAGE_VALUE_0, FUNGIBLE_ASSET_AMOUNT,
     NON_FUNGIBLE_ASSET_DATA, STORAGE_ITEM_0, STORAGE_ITEM_1,
 };
@@ -30,27 +37,47 @@ fn mock_account_vault() -> AccountVault {
 }
 
 fn mock_account_storage() -> AccountStorage {
-    // Create an account merkle store
+    let child_smt = mock_child_smt();
     let mut account_merkle_store = MerkleStore::new();
-    let child_smt =
-        SimpleSmt::with_leaves(CHILD_SMT_DEPTH, [(CHILD_STORAGE_INDEX_0, CHILD_STORAGE_VALUE_0)])
-            .unwrap();
     account_merkle_store.extend(child_smt.inner_nodes());
 
     // create account storage
+    let child_root =
+        StorageItem::new_array(CHILD_ROOT_PARENT_LEAF_INDEX, CHILD_SMT_DEPTH, *child_smt.root());
     AccountStorage::new(
-        vec![
-            STORAGE_ITEM_0,
-            STORAGE_ITEM_1,
-            (CHILD_ROOT_PARENT_LEAF_INDEX, *child_smt.root()),
-        ],
+        vec![STORAGE_ITEM_0, STORAGE_ITEM_1, child_root],
+        vec![],
         account_merkle_store,
     )
     .unwrap()
 }
 
-fn mock_account_code(account_id: &AccountId, assembler: &mut Assembler) -> AccountCode {
-    let account_code = "\
+/// Returns the storage of the mock new account.
+///
+/// The precomputed seed of the mock new account was ground for an all-value slot layout, so the
+/// child tree root is declared as a value slot.
+fn mock_new_account_storage() -> AccountStorage {
+    let child_smt = mock_child_smt();
+    let mut account_merkle_store = MerkleStore::new();
+    account_merkle_store.extend(child_smt.inner_nodes());
+
+    // create account storage
+    let child_root = StorageItem::new_value(CHILD_ROOT_PARENT_LEAF_INDEX, *child_smt.root());
+    AccountStorage::new(
+        vec![STORAGE_ITEM_0, STORAGE_ITEM_1, child_root],
+        vec![],
+        account_merkle_store,
+    )
+    .unwrap()
+}
+
+fn mock_child_smt() -> SimpleSmt {
+    SimpleSmt::with_leaves(CHILD_SMT_DEPTH, [(CHILD_STORAGE_INDEX_0, CHILD_STORAGE_VALUE_0)])
+        .unwrap()
+}
+
+/// Code of the mock new account, which is also shared by the other mock accounts.
+const MOCK_NEW_ACCOUNT_CODE: &str = "\
             use.miden::sat::account
 
             export.incr_nonce
@@ -87,16 +114,33 @@ fn mock_account_code(account_id: &AccountId, assembler: &mut Assembler) -> Accou
                 sub
             end
             ";
-    let account_module_ast = ModuleAst::parse(account_code).unwrap();
+
+/// Procedures of the mock account which update its array slot. These are not part of the code of
+/// the mock new account, as its precomputed seed commits to its code root.
+const MOCK_ACCOUNT_ARRAY_PROCEDURES: &str = "
+            export.set_array_item
+                exec.account::set_array_item
+                # => [OLD_ARRAY_ROOT, OLD_VALUE]
+            end
+            ";
+
+fn mock_account_code(account_id: &AccountId, assembler: &mut Assembler) -> AccountCode {
+    let account_code = format!("{MOCK_NEW_ACCOUNT_CODE}{MOCK_ACCOUNT_ARRAY_PROCEDURES}");
+    let account_module_ast = ModuleAst::parse(&account_code).unwrap();
+    AccountCode::new(*account_id, account_module_ast, assembler).unwrap()
+}
+
+fn mock_new_account_code(account_id: &AccountId, assembler: &mut Assembler) -> AccountCode {
+    let account_module_ast = ModuleAst::parse(MOCK_NEW_ACCOUNT_CODE).unwrap();
     AccountCode::new(*account_id, account_module_ast, assembler).unwrap()
 }
 
 pub fn mock_new_account(assembler: &mut Assembler) -> Account {
-    let account_storage = mock_account_storage();
+    let account_storage = mock_new_account_storage();
     let account_id =
-        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
-    let account_code = mock_account_code(&account_id, assembler);
-    let account_seed: Word = ACCOUNT_SEED_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let account_code = mock_new_account_code(&account_id, assembler);
+    let account_seed: Word = ACCOUNT_SEED_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN
         .iter()
         .map(|x| Felt::new(*x))
         .collect::<Vec<_>>()
@@ -130,6 +174,102 @@ pub fn mock_account(nonce: Felt, code: Option<AccountCode>, assembler: &mut Asse
     Account::new(account_id, account_vault, account_storage, account_code, nonce)
 }
 
+pub fn mock_fungible_faucet(total_issuance: u64, assembler: &mut Assembler) -> Account {
+    let account_id: AccountId = ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN.try_into().unwrap();
+
+    // the faucet storage tracks the total issuance of the faucet asset
+    let account_storage = AccountStorage::new(
+        vec![StorageItem::new_value(
+            AccountStorage::FAUCET_ISSUANCE_SLOT,
+            [Felt::new(total_issuance), Felt::ZERO, Felt::ZERO, Felt::ZERO],
+        )],
+        vec![],
+        MerkleStore::new(),
+    )
+    .unwrap();
+    let account_code = mock_account_code(&account_id, assembler);
+
+    Account::new(account_id, AccountVault::default(), account_storage, account_code, Felt::ONE)
+}
+
+/// Returns an existing basic wallet holding the specified vault, owned by the holder of the secret
+/// key associated with the specified public key.
+pub fn mock_basic_wallet(
+    pub_key: Word,
+    vault: AccountVault,
+    nonce: Felt,
+    assembler: &mut Assembler,
+) -> Account {
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+
+    let account_storage = AccountStorage::new(
+        vec![StorageItem::new_value(BASIC_WALLET_PUBLIC_KEY_SLOT, pub_key)],
+        vec![],
+        MerkleStore::new(),
+    )
+    .unwrap();
+    let account_module_ast = ModuleAst::parse(BASIC_WALLET_CODE).unwrap();
+    let account_code = AccountCode::new(account_id, account_module_ast, assembler).unwrap();
+
+    Account::new(account_id, vault, account_storage, account_code, nonce)
+}
+
+/// Returns an existing basic fungible faucet issuing the "POL" token with 8 decimals, owned by the
+/// holder of the secret key associated with the specified public key.
+pub fn mock_basic_fungible_faucet(
+    pub_key: Word,
+    max_supply: u64,
+    total_issuance: u64,
+    assembler: &mut Assembler,
+) -> Account {
+    let account_id: AccountId = ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN.try_into().unwrap();
+
+    let token_symbol = encode_token_symbol("POL").unwrap();
+    let account_storage = AccountStorage::new(
+        vec![
+            StorageItem::new_value(BASIC_WALLET_PUBLIC_KEY_SLOT, pub_key),
+            StorageItem::new_value(
+                FAUCET_METADATA_SLOT,
+                [Felt::new(max_supply), Felt::new(8), token_symbol, Felt::ZERO],
+            ),
+            StorageItem::new_value(
+                AccountStorage::FAUCET_ISSUANCE_SLOT,
+                [Felt::new(total_issuance), Felt::ZERO, Felt::ZERO, Felt::ZERO],
+            ),
+        ],
+        vec![],
+        MerkleStore::new(),
+    )
+    .unwrap();
+    let account_module_ast = ModuleAst::parse(BASIC_FUNGIBLE_FAUCET_CODE).unwrap();
+    let account_code = AccountCode::new(account_id, account_module_ast, assembler).unwrap();
+
+    Account::new(account_id, AccountVault::default(), account_storage, account_code, Felt::ONE)
+}
+
+pub fn mock_non_fungible_faucet(
+    issued_assets: &[NonFungibleAsset],
+    assembler: &mut Assembler,
+) -> Account {
+    let account_id: AccountId = ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN.try_into().unwrap();
+
+    // the faucet storage tracks the issued assets in a map keyed by the assets themselves
+    let issued_assets = StorageMap::with_entries(
+        issued_assets.iter().map(|asset| ((*asset).into(), (*asset).into())),
+    )
+    .unwrap();
+    let account_storage = AccountStorage::new(
+        vec![],
+        vec![(AccountStorage::FAUCET_ISSUANCE_SLOT, issued_assets)],
+        MerkleStore::new(),
+    )
+    .unwrap();
+    let account_code = mock_account_code(&account_id, assembler);
+
+    Account::new(account_id, AccountVault::default(), account_storage, account_code, Felt::ONE)
+}
+
 #[derive(Debug, PartialEq)]
 pub enum AccountStatus {
     New,
@@ -2,25 +2,27 @@ use miden_core::FieldElement;
 
 use super::{Felt, StorageItem};
 
-pub const ACCOUNT_SEED_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN: [u64; 4] = [
+pub const ACCOUNT_SEED_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN: [u64; 4] = [
     5950491586293629690,
     3173174058297886549,
     16553747801483039178,
     11841717777847436894,
 ];
-pub const ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN: u64 = 3972335011818762557;
-pub const ACCOUNT_ID_SENDER: u64 = 0b0110111011u64 << 54;
+pub const ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN: u64 = 3972335011818762557;
+pub const ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN: u64 = 513570497998221629;
+pub const ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN: u64 = 0b0100111100u64 << 54;
+pub const ACCOUNT_ID_SENDER: u64 = 0b0100111011u64 << 54;
 
-pub const ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN: u64 = 0b1010011100 << 54;
-pub const ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN: u64 = 0b1110011100 << 54;
+pub const ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN: u64 = 0b1000111100 << 54;
+pub const ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN: u64 = 0b1100011100 << 54;
 pub const FUNGIBLE_ASSET_AMOUNT: u64 = 1000;
 
 pub const ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1: u64 =
-    0b1010010001111111010110100011011110101011010001101111110110111100u64;
+    0b1000010001111111010110100011011110101011010001101111110110111100u64;
 pub const ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2: u64 =
-    0b1010000101101010101101000110111101010110100011011110100011011101u64;
+    0b1000000101101010101101000110111101010110100011011110100011011101u64;
 pub const ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_3: u64 =
-    0b1010011001011010101101000110111101010110100011011101000110111100u64;
+    0b1000011001011010101101000110111101010110100011011101000110111100u64;
 
 pub const NON_FUNGIBLE_ASSET_DATA: [u8; 4] = [1, 2, 3, 4];
 
@@ -30,8 +32,8 @@ pub const STORAGE_INDEX_0: u8 = 20;
 pub const STORAGE_VALUE_0: [Felt; 4] = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
 pub const STORAGE_INDEX_1: u8 = 30;
 pub const STORAGE_VALUE_1: [Felt; 4] = [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)];
-pub const STORAGE_ITEM_0: StorageItem = (STORAGE_INDEX_0, STORAGE_VALUE_0);
-pub const STORAGE_ITEM_1: StorageItem = (STORAGE_INDEX_1, STORAGE_VALUE_1);
+pub const STORAGE_ITEM_0: StorageItem = StorageItem::new_value(STORAGE_INDEX_0, STORAGE_VALUE_0);
+pub const STORAGE_ITEM_1: StorageItem = StorageItem::new_value(STORAGE_INDEX_1, STORAGE_VALUE_1);
 
 pub const CHILD_ROOT_PARENT_LEAF_INDEX: u8 = 10;
 pub const CHILD_SMT_DEPTH: u8 = 64;
@@ -6,11 +6,10 @@ use crate::mock::{
 
 use super::super::{
     assets::{Asset, FungibleAsset},
-    notes::{Note, NoteScript},
+    notes::{Note, NoteScript, NoteTag},
     AccountId, Felt, Vec, Word,
 };
 use assembly::{ast::ProgramAst, Assembler};
-use miden_core::FieldElement;
 
 pub enum AssetPreservationStatus {
     TooFewInput,
@@ -47,7 +46,7 @@ pub fn mock_notes(
         &[fungible_asset_1],
         SERIAL_NUM_4,
         sender,
-        Felt::ZERO,
+        NoteTag::try_from(0).unwrap(),
         None,
     )
     .unwrap();
@@ -59,7 +58,7 @@ pub fn mock_notes(
         &[fungible_asset_2],
         SERIAL_NUM_5,
         sender,
-        Felt::ZERO,
+        NoteTag::try_from(0).unwrap(),
         None,
     )
     .unwrap();
@@ -71,7 +70,7 @@ pub fn mock_notes(
         &[fungible_asset_3],
         SERIAL_NUM_6,
         sender,
-        Felt::ZERO,
+        NoteTag::try_from(0).unwrap(),
         None,
     )
     .unwrap();
@@ -142,7 +141,7 @@ pub fn mock_notes(
         &[fungible_asset_1],
         SERIAL_NUM_1,
         sender,
-        Felt::ZERO,
+        NoteTag::try_from(0).unwrap(),
         None,
     )
     .unwrap();
@@ -154,7 +153,7 @@ pub fn mock_notes(
         &[fungible_asset_2, fungible_asset_3],
         SERIAL_NUM_2,
         sender,
-        Felt::ZERO,
+        NoteTag::try_from(0).unwrap(),
         None,
     )
     .unwrap();
@@ -169,7 +168,7 @@ pub fn mock_notes(
         &[fungible_asset_2, fungible_asset_3],
         SERIAL_NUM_3,
         sender,
-        Felt::ZERO,
+        NoteTag::try_from(0).unwrap(),
         None,
     )
     .unwrap();
@@ -1,11 +1,15 @@
+use assembly::ast::ProgramAst;
 use miden_core::FieldElement;
 
 use super::{
     super::{
-        notes::Note, transaction::ExecutedTransaction, Account, BlockHeader, ChainMmr, Felt, Vec,
+        assets::Asset,
+        notes::{Note, NoteScript, NoteTag},
+        transaction::ExecutedTransaction,
+        Account, AccountId, BlockHeader, ChainMmr, Felt, Vec, Word,
     },
     assembler, mock_account, mock_block_header, mock_chain_data, mock_new_account, mock_notes,
-    AccountStatus, AssetPreservationStatus,
+    AccountStatus, AssetPreservationStatus, ACCOUNT_ID_SENDER,
 };
 
 pub fn mock_inputs(account_status: AccountStatus) -> (Account, BlockHeader, ChainMmr, Vec<Note>) {
@@ -38,6 +42,45 @@ pub fn mock_inputs(account_status: AccountStatus) -> (Account, BlockHeader, Chai
     (account, block_header, chain_mmr, consumed_notes)
 }
 
+pub fn mock_inputs_with_note_assets(
+    account: Account,
+    note_assets: &[Asset],
+) -> (Account, BlockHeader, ChainMmr, Vec<Note>) {
+    // Create assembler and assembler context
+    let mut assembler = assembler();
+
+    // a single consumed note which provides the specified assets
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let note_program_ast = ProgramAst::parse("begin push.1 drop end").unwrap();
+    let (note_script, _) = NoteScript::new(note_program_ast, &mut assembler).unwrap();
+    const SERIAL_NUM: Word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let note = Note::new(
+        note_script,
+        &[Felt::new(1)],
+        note_assets,
+        SERIAL_NUM,
+        sender,
+        NoteTag::try_from(0).unwrap(),
+        None,
+    )
+    .unwrap();
+    let mut consumed_notes = vec![note];
+
+    // Chain data
+    let chain_mmr: ChainMmr = mock_chain_data(&mut consumed_notes);
+
+    // Block header
+    let block_header = mock_block_header(
+        Felt::new(4),
+        Some(chain_mmr.mmr().accumulator().hash_peaks().into()),
+        None,
+        &[account.clone()],
+    );
+
+    // Transaction inputs
+    (account, block_header, chain_mmr, consumed_notes)
+}
+
 pub fn mock_executed_tx(asset_preservation: AssetPreservationStatus) -> ExecutedTransaction {
     // Create assembler and assembler context
     let mut assembler = assembler();
@@ -1,20 +1,20 @@
-use super::{AccountId, Felt, NoteError, Word};
+use super::{AccountId, Felt, NoteError, NoteTag, Word};
 
 /// Represents metadata associated with a note. This includes the sender, tag, and number of assets.
 /// - sender is the account which created the note.
-/// - tag is a tag which can be used to identify the target account for the note.
+/// - tag is a tag which can be used to route the note to the accounts interested in consuming it.
 /// - num_assets is the number of assets in the note.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct NoteMetadata {
     sender: AccountId,
-    tag: Felt,
+    tag: NoteTag,
     num_assets: Felt,
 }
 
 impl NoteMetadata {
     /// Returns a new note metadata object created with the specified parameters.
-    pub fn new(sender: AccountId, tag: Felt, num_assets: Felt) -> Self {
+    pub fn new(sender: AccountId, tag: NoteTag, num_assets: Felt) -> Self {
         // TODO: Assert num assets is valid
         Self {
             sender,
@@ -29,7 +29,7 @@ impl NoteMetadata {
     }
 
     /// Returns the tag associated with the note.
-    pub fn tag(&self) -> Felt {
+    pub fn tag(&self) -> NoteTag {
         self.tag
     }
 
@@ -43,7 +43,7 @@ impl From<&NoteMetadata> for Word {
     fn from(metadata: &NoteMetadata) -> Self {
         let mut elements = Word::default();
         elements[0] = metadata.num_assets;
-        elements[1] = metadata.tag;
+        elements[1] = metadata.tag.into();
         elements[2] = metadata.sender.into();
         elements
     }
@@ -56,7 +56,7 @@ impl TryFrom<Word> for NoteMetadata {
         // TODO: Assert num assets is valid
         Ok(Self {
             sender: elements[2].try_into().map_err(NoteError::NoteMetadataSenderInvalid)?,
-            tag: elements[1],
+            tag: elements[1].try_into()?,
             num_assets: elements[0],
         })
     }
@@ -1,6 +1,6 @@
 use super::{
     assets::Asset, AccountId, Assembler, AssemblyContext, AssemblyContextType, CodeBlock, Digest,
-    Felt, Hasher, NoteError, ProgramAst, ToString, Vec, Word, WORD_SIZE, ZERO,
+    Felt, Hasher, NoteError, ProgramAst, StarkField, ToString, Vec, Word, WORD_SIZE, ZERO,
 };
 
 mod envelope;
@@ -18,12 +18,20 @@ pub use origin::{NoteInclusionProof, NoteOrigin};
 mod script;
 pub use script::NoteScript;
 
+pub mod standard;
+
 mod stub;
 pub use stub::NoteStub;
 
+mod tag;
+pub use tag::{NoteExecutionHint, NoteTag};
+
 mod vault;
 pub use vault::NoteVault;
 
+#[cfg(test)]
+mod tests;
+
 // CONSTANTS
 // ================================================================================================
 
@@ -81,7 +89,7 @@ impl Note {
         assets: &[Asset],
         serial_num: Word,
         sender: AccountId,
-        tag: Felt,
+        tag: NoteTag,
         proof: Option<NoteInclusionProof>,
     ) -> Result<Self, NoteError> {
         let vault = NoteVault::new(assets)?;
@@ -138,9 +146,18 @@ impl Note {
     /// Recipient is defined and calculated as:
     ///  hash(hash(hash(serial_num, [0; 4]), script_hash), input_hash)
     pub fn recipient(&self) -> Digest {
-        let serial_num_hash = Hasher::merge(&[self.serial_num.into(), Digest::default()]);
-        let merge_script = Hasher::merge(&[serial_num_hash, self.script.hash()]);
-        Hasher::merge(&[merge_script, self.inputs.hash()])
+        Self::compute_recipient(self.serial_num, self.script.hash(), self.inputs.hash())
+    }
+
+    /// Returns the recipient of a note with the specified serial number, script hash and inputs
+    /// hash, computed as described in [Note::recipient()].
+    ///
+    /// This can be used to compute the recipient of a note which is to be created by a script,
+    /// e.g., the payback note of a swap note.
+    pub fn compute_recipient(serial_num: Word, script_hash: Digest, inputs_hash: Digest) -> Digest {
+        let serial_num_hash = Hasher::merge(&[serial_num.into(), Digest::default()]);
+        let merge_script = Hasher::merge(&[serial_num_hash, script_hash]);
+        Hasher::merge(&[merge_script, inputs_hash])
     }
 
     /// Returns a commitment to this note.
@@ -0,0 +1,111 @@
+use super::{
+    AccountId, Assembler, Asset, Felt, Note, NoteError, NoteExecutionHint, NoteInputs, NoteScript,
+    NoteTag, ProgramAst, Vec, Word,
+};
+use miden_lib::StandardNotes;
+
+#[cfg(test)]
+mod tests;
+
+// STANDARD NOTES
+// ================================================================================================
+
+/// Returns a new pay-to-ID note which can be consumed only by the target account.
+///
+/// The note carries the specified assets and is tagged with the target account ID prefix. The
+/// account consuming the note must expose the `miden::wallets::basic::receive_asset` procedure.
+///
+/// The provided assembler must be instantiated with the Miden library, the standard library and
+/// the transaction kernel.
+///
+/// # Errors
+/// Returns an error if the list of assets is empty, contains duplicates, or exceeds the maximum
+/// number of assets in a note.
+pub fn create_p2id_note(
+    sender: AccountId,
+    target: AccountId,
+    assets: &[Asset],
+    serial_num: Word,
+    assembler: &Assembler,
+) -> Result<Note, NoteError> {
+    let note_script = compile_note_script(StandardNotes::p2id(), assembler)?;
+    let inputs = [target.into()];
+    let tag = NoteTag::from_account_id(target, NoteExecutionHint::Local)?;
+
+    Note::new(note_script, &inputs, assets, serial_num, sender, tag, None)
+}
+
+/// Returns a new pay-to-ID-with-reclaim note which can be consumed by the target account at any
+/// time, or by the sender once the block with the specified number has been reached.
+///
+/// The note carries the specified assets and is tagged with the target account ID prefix. The
+/// account consuming the note must expose the `miden::wallets::basic::receive_asset` procedure.
+///
+/// The provided assembler must be instantiated with the Miden library, the standard library and
+/// the transaction kernel.
+///
+/// # Errors
+/// Returns an error if the list of assets is empty, contains duplicates, or exceeds the maximum
+/// number of assets in a note.
+pub fn create_p2idr_note(
+    sender: AccountId,
+    target: AccountId,
+    assets: &[Asset],
+    reclaim_block_height: u32,
+    serial_num: Word,
+    assembler: &Assembler,
+) -> Result<Note, NoteError> {
+    let note_script = compile_note_script(StandardNotes::p2idr(), assembler)?;
+    let inputs = [Felt::from(reclaim_block_height), target.into()];
+    let tag = NoteTag::from_account_id(target, NoteExecutionHint::Local)?;
+
+    Note::new(note_script, &inputs, assets, serial_num, sender, tag, None)
+}
+
+/// Returns a new swap note which offers the `offered_asset` in exchange for the `requested_asset`.
+///
+/// The account consuming the note receives the offered asset and sends the requested asset back
+/// to the sender in a pay-to-ID payback note created with `payback_serial_num`. The account
+/// consuming the note must expose the `miden::wallets::basic::receive_asset` and
+/// `miden::wallets::basic::send_asset` procedures.
+///
+/// The provided assembler must be instantiated with the Miden library, the standard library and
+/// the transaction kernel.
+///
+/// # Errors
+/// Returns an error if the note could not be created.
+pub fn create_swap_note(
+    sender: AccountId,
+    offered_asset: Asset,
+    requested_asset: Asset,
+    tag: NoteTag,
+    serial_num: Word,
+    payback_serial_num: Word,
+    assembler: &Assembler,
+) -> Result<Note, NoteError> {
+    let note_script = compile_note_script(StandardNotes::swap(), assembler)?;
+
+    // the payback note is a pay-to-ID note targeting the sender of the swap note
+    let payback_script = compile_note_script(StandardNotes::p2id(), assembler)?;
+    let payback_inputs = NoteInputs::new(&[sender.into()]);
+    let payback_recipient =
+        Note::compute_recipient(payback_serial_num, payback_script.hash(), payback_inputs.hash());
+
+    // the inputs are placed onto the stack as [REQUESTED_ASSET, payback_tag, PAYBACK_RECIPIENT]
+    let mut inputs = Vec::with_capacity(9);
+    inputs.extend_from_slice(payback_recipient.as_elements());
+    inputs.push(NoteTag::from_account_id(sender, NoteExecutionHint::Local)?.into());
+    inputs.extend_from_slice(&Word::from(requested_asset));
+
+    Note::new(note_script, &inputs, &[offered_asset], serial_num, sender, tag, None)
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Compiles the provided note script source code into a [NoteScript].
+fn compile_note_script(source: &str, assembler: &Assembler) -> Result<NoteScript, NoteError> {
+    let note_script_ast = ProgramAst::parse(source).expect("standard note script is well formed");
+    let (note_script, _) = NoteScript::new(note_script_ast, assembler)?;
+    Ok(note_script)
+}
@@ -0,0 +1,91 @@
+use super::{
+    create_p2id_note, create_p2idr_note, create_swap_note, AccountId, Asset, Felt,
+    NoteExecutionHint, NoteTag, Word,
+};
+use crate::{
+    assets::FungibleAsset,
+    mock::{
+        assembler, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
+        ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, ACCOUNT_ID_SENDER,
+    },
+};
+
+const SERIAL_NUM_1: Word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+const SERIAL_NUM_2: Word = [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)];
+
+fn fungible_asset(faucet_id: u64, amount: u64) -> Asset {
+    let faucet_id = AccountId::try_from(faucet_id).unwrap();
+    FungibleAsset::new(faucet_id, amount).unwrap().into()
+}
+
+#[test]
+fn test_create_p2id_note() {
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let asset = fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, 100);
+    let assembler = assembler();
+
+    let note_1 = create_p2id_note(sender, target, &[asset], SERIAL_NUM_1, &assembler).unwrap();
+    let note_2 = create_p2id_note(target, sender, &[asset], SERIAL_NUM_2, &assembler).unwrap();
+
+    // the target account ID is placed at the top of the stack
+    assert_eq!(note_1.inputs().inputs()[15], Felt::from(target));
+    assert!(note_1.metadata().tag().matches_account(target));
+    assert_eq!(note_1.metadata().tag().execution_hint(), NoteExecutionHint::Local);
+    assert_eq!(note_1.metadata().sender(), sender);
+    assert_eq!(note_1.serial_num(), SERIAL_NUM_1);
+
+    // all pay-to-ID notes share the same script
+    assert_eq!(note_1.script().hash(), note_2.script().hash());
+    assert_ne!(note_1.inputs().hash(), note_2.inputs().hash());
+}
+
+#[test]
+fn test_create_p2idr_note() {
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let asset = fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, 100);
+    let assembler = assembler();
+
+    let p2id_note = create_p2id_note(sender, target, &[asset], SERIAL_NUM_1, &assembler).unwrap();
+    let note = create_p2idr_note(sender, target, &[asset], 10, SERIAL_NUM_1, &assembler).unwrap();
+
+    // the target account ID is placed at the top of the stack, followed by the reclaim height
+    assert_eq!(note.inputs().inputs()[15], Felt::from(target));
+    assert_eq!(note.inputs().inputs()[14], Felt::new(10));
+    assert!(note.metadata().tag().matches_account(target));
+    assert_ne!(note.script().hash(), p2id_note.script().hash());
+}
+
+#[test]
+fn test_create_swap_note() {
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let consumer = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let offered_asset = fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, 100);
+    let requested_asset = fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2, 200);
+    let assembler = assembler();
+
+    let note = create_swap_note(
+        sender,
+        offered_asset,
+        requested_asset,
+        NoteTag::try_from(7).unwrap(),
+        SERIAL_NUM_1,
+        SERIAL_NUM_2,
+        &assembler,
+    )
+    .unwrap();
+
+    assert_eq!(note.vault().iter().collect::<Vec<_>>(), vec![&offered_asset]);
+    assert_eq!(note.metadata().tag(), NoteTag::try_from(7).unwrap());
+
+    // the payback note created by the consumer of the swap note must be a pay-to-ID note which
+    // sends the requested asset to the sender of the swap note
+    let payback_note =
+        create_p2id_note(consumer, sender, &[requested_asset], SERIAL_NUM_2, &assembler).unwrap();
+
+    let inputs = note.inputs().inputs();
+    assert_eq!(&inputs[12..], &Word::from(requested_asset));
+    assert_eq!(inputs[11], Felt::from(payback_note.metadata().tag()));
+    assert_eq!(&inputs[7..11], payback_note.recipient().as_elements());
+}
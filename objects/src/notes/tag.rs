@@ -0,0 +1,197 @@
+use super::{AccountId, Felt, NoteError, StarkField};
+use core::fmt;
+
+// NOTE EXECUTION HINT
+// ================================================================================================
+
+/// Specifies where a note is expected to be consumed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum NoteExecutionHint {
+    /// The note is expected to be consumed by a user in a locally executed transaction.
+    Local,
+    /// The note is expected to be consumed by the network operator.
+    Network,
+}
+
+// NOTE TAG
+// ================================================================================================
+
+/// A tag used to route a note to the accounts interested in consuming it.
+///
+/// A note tag is a 32-bit value laid out as follows:
+/// - The most significant bit is the execution hint: ZERO for local execution and ONE for network
+///   execution.
+/// - The second most significant bit specifies the kind of the tag:
+///   - ZERO for a tag targeting an account. The remaining 30 bits are set to the 30 most
+///     significant bits of the target account ID.
+///   - ONE for a use case tag. The next 14 bits are set to the use case ID and the 16 least
+///     significant bits to a use case specific payload.
+///
+/// A tag targeting an account is not well-formed if the encoded account ID prefix specifies an
+/// invalid storage mode, or if the tag requests network execution against a private account, as the
+/// network operator does not have access to the state of private accounts. The same checks are
+/// performed by the transaction kernel when a note is created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct NoteTag(u32);
+
+impl NoteTag {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+    /// The number of most significant bits of the account ID encoded in an account target tag.
+    pub const ACCOUNT_ID_PREFIX_BITS: u32 = 30;
+
+    /// The maximum use case ID of a use case tag.
+    pub const MAX_USE_CASE_ID: u16 = (1 << 14) - 1;
+
+    const NETWORK_EXECUTION_FLAG: u32 = 1 << 31;
+    const USE_CASE_FLAG: u32 = 1 << 30;
+    const USE_CASE_ID_SHIFT: u32 = 16;
+    const STORAGE_MODE_SHIFT: u32 = Self::ACCOUNT_ID_PREFIX_BITS - 4;
+    const INVALID_STORAGE_MODE_TAG: u32 = 0b10;
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+    /// Returns a tag targeting the specified account.
+    ///
+    /// The tag is derived from the 30 most significant bits of the account ID, and thus may be
+    /// shared by several accounts.
+    ///
+    /// # Errors
+    /// Returns an error if network execution is requested for a private account.
+    pub fn from_account_id(
+        account_id: AccountId,
+        execution_hint: NoteExecutionHint,
+    ) -> Result<Self, NoteError> {
+        let prefix = (u64::from(account_id) >> (64 - Self::ACCOUNT_ID_PREFIX_BITS)) as u32;
+        Self::try_from(Self::execution_flag(execution_hint) | prefix)
+    }
+
+    /// Returns a use case tag with the specified use case ID and payload.
+    ///
+    /// # Errors
+    /// Returns an error if the use case ID is greater than [NoteTag::MAX_USE_CASE_ID].
+    pub fn for_use_case(
+        use_case_id: u16,
+        payload: u16,
+        execution_hint: NoteExecutionHint,
+    ) -> Result<Self, NoteError> {
+        if use_case_id > Self::MAX_USE_CASE_ID {
+            return Err(NoteError::NoteTagUseCaseIdTooLarge(use_case_id));
+        }
+
+        let use_case = ((use_case_id as u32) << Self::USE_CASE_ID_SHIFT) | payload as u32;
+        Ok(Self(Self::execution_flag(execution_hint) | Self::USE_CASE_FLAG | use_case))
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the execution hint of this tag.
+    pub fn execution_hint(&self) -> NoteExecutionHint {
+        if self.0 & Self::NETWORK_EXECUTION_FLAG == 0 {
+            NoteExecutionHint::Local
+        } else {
+            NoteExecutionHint::Network
+        }
+    }
+
+    /// Returns true if this tag targets an account.
+    pub fn is_account_target(&self) -> bool {
+        self.0 & Self::USE_CASE_FLAG == 0
+    }
+
+    /// Returns true if this is a use case tag.
+    pub fn is_use_case(&self) -> bool {
+        !self.is_account_target()
+    }
+
+    /// Returns the use case ID of this tag, or None if this is not a use case tag.
+    pub fn use_case_id(&self) -> Option<u16> {
+        self.is_use_case()
+            .then_some(((self.0 >> Self::USE_CASE_ID_SHIFT) as u16) & Self::MAX_USE_CASE_ID)
+    }
+
+    /// Returns the use case payload of this tag, or None if this is not a use case tag.
+    pub fn use_case_payload(&self) -> Option<u16> {
+        self.is_use_case().then_some(self.0 as u16)
+    }
+
+    /// Returns true if this tag targets the specified account.
+    ///
+    /// As the tag only encodes a prefix of the account ID, the tag may match other accounts with
+    /// the same prefix as well.
+    pub fn matches_account(&self, account_id: AccountId) -> bool {
+        self.is_account_target()
+            && Self::from_account_id(account_id, self.execution_hint()) == Ok(*self)
+    }
+
+    /// Returns true if this is a use case tag with the specified use case ID.
+    pub fn matches_use_case(&self, use_case_id: u16) -> bool {
+        self.use_case_id() == Some(use_case_id)
+    }
+
+    /// Returns the 32-bit value of this tag.
+    pub fn inner(&self) -> u32 {
+        self.0
+    }
+
+    // HELPER FUNCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    fn execution_flag(execution_hint: NoteExecutionHint) -> u32 {
+        match execution_hint {
+            NoteExecutionHint::Local => 0,
+            NoteExecutionHint::Network => Self::NETWORK_EXECUTION_FLAG,
+        }
+    }
+}
+
+impl TryFrom<u32> for NoteTag {
+    type Error = NoteError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        let tag = Self(value);
+        if tag.is_account_target() {
+            let storage_mode = (value >> Self::STORAGE_MODE_SHIFT) & 0b11;
+            let is_private = storage_mode == AccountId::PRIVATE_STORAGE_MODE_TAG as u32;
+            if storage_mode == Self::INVALID_STORAGE_MODE_TAG
+                || (is_private && tag.execution_hint() == NoteExecutionHint::Network)
+            {
+                return Err(NoteError::NoteTagInvalid(value as u64));
+            }
+        }
+
+        Ok(tag)
+    }
+}
+
+impl From<NoteTag> for u32 {
+    fn from(tag: NoteTag) -> Self {
+        tag.0
+    }
+}
+
+impl From<NoteTag> for Felt {
+    fn from(tag: NoteTag) -> Self {
+        Felt::from(tag.0)
+    }
+}
+
+impl TryFrom<Felt> for NoteTag {
+    type Error = NoteError;
+
+    fn try_from(value: Felt) -> Result<Self, Self::Error> {
+        u32::try_from(value.as_int())
+            .map_err(|_| NoteError::NoteTagInvalid(value.as_int()))
+            .and_then(Self::try_from)
+    }
+}
+
+impl fmt::Display for NoteTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
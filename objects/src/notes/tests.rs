@@ -0,0 +1,87 @@
+use super::{AccountId, Felt, NoteError, NoteExecutionHint, NoteMetadata, NoteTag, Word};
+use crate::mock::{
+    ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+    ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, ACCOUNT_ID_SENDER,
+};
+
+#[test]
+fn test_note_tag_account_target() {
+    let target = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+
+    let tag = NoteTag::from_account_id(target, NoteExecutionHint::Local).unwrap();
+    assert!(tag.is_account_target());
+    assert_eq!(tag.execution_hint(), NoteExecutionHint::Local);
+    assert_eq!(tag.inner() as u64, ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN >> 34);
+    assert!(tag.matches_account(target));
+    assert!(!tag.matches_account(sender));
+    assert_eq!(tag.use_case_id(), None);
+
+    let tag = NoteTag::from_account_id(target, NoteExecutionHint::Network).unwrap();
+    assert_eq!(tag.execution_hint(), NoteExecutionHint::Network);
+    assert!(tag.matches_account(target));
+}
+
+#[test]
+fn test_note_tag_account_target_validation() {
+    // network execution can not be requested for a private account
+    let target = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let tag = NoteTag::from_account_id(target, NoteExecutionHint::Local).unwrap();
+    let network_tag = tag.inner() | 1 << 31;
+    assert_eq!(
+        NoteTag::from_account_id(target, NoteExecutionHint::Network),
+        Err(NoteError::NoteTagInvalid(network_tag as u64))
+    );
+    assert_eq!(
+        NoteTag::try_from(network_tag),
+        Err(NoteError::NoteTagInvalid(network_tag as u64))
+    );
+
+    // the storage mode of the target account must be valid
+    let invalid_storage_mode_tag = 0b10 << 26;
+    assert_eq!(
+        NoteTag::try_from(invalid_storage_mode_tag),
+        Err(NoteError::NoteTagInvalid(invalid_storage_mode_tag as u64))
+    );
+
+    // use case tags are not subject to the account target checks
+    assert!(NoteTag::try_from(network_tag | 1 << 30).is_ok());
+}
+
+#[test]
+fn test_note_tag_use_case() {
+    let tag = NoteTag::for_use_case(NoteTag::MAX_USE_CASE_ID, 0xabcd, NoteExecutionHint::Network)
+        .unwrap();
+    assert!(tag.is_use_case());
+    assert_eq!(tag.execution_hint(), NoteExecutionHint::Network);
+    assert_eq!(tag.use_case_id(), Some(NoteTag::MAX_USE_CASE_ID));
+    assert_eq!(tag.use_case_payload(), Some(0xabcd));
+    assert!(tag.matches_use_case(NoteTag::MAX_USE_CASE_ID));
+    assert!(!tag.matches_use_case(1));
+
+    let target = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    assert!(!tag.matches_account(target));
+
+    assert_eq!(
+        NoteTag::for_use_case(NoteTag::MAX_USE_CASE_ID + 1, 0, NoteExecutionHint::Local),
+        Err(NoteError::NoteTagUseCaseIdTooLarge(NoteTag::MAX_USE_CASE_ID + 1))
+    );
+}
+
+#[test]
+fn test_note_metadata_tag_validation() {
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let tag = NoteTag::try_from(u32::MAX).unwrap();
+    let metadata = NoteMetadata::new(sender, tag, Felt::new(1));
+
+    let word = Word::from(&metadata);
+    assert_eq!(word[1], Felt::new(u32::MAX as u64));
+    assert_eq!(NoteMetadata::try_from(word), Ok(metadata));
+
+    let mut word = word;
+    word[1] = Felt::new(u32::MAX as u64 + 1);
+    assert_eq!(
+        NoteMetadata::try_from(word),
+        Err(NoteError::NoteTagInvalid(u32::MAX as u64 + 1))
+    );
+}
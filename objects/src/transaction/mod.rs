@@ -1,9 +1,10 @@
 use super::{
     notes::{Note, NoteEnvelope, NoteStub},
-    Account, AccountDelta, AccountError, AccountId, AccountStorage, AccountStub, AdviceInputs,
-    AdviceInputsBuilder, BTreeMap, BlockHeader, ChainMmr, Digest, Felt, Hasher, MerkleStore,
-    PreparedTransactionError, StarkField, ToAdviceInputs, TransactionResultError,
-    TransactionWitnessError, TryFromVmResult, Vec, Word, WORD_SIZE, ZERO,
+    Account, AccountCode, AccountDelta, AccountError, AccountId, AccountStorage,
+    AccountStorageMode, AccountStub, AdviceInputs, AdviceInputsBuilder, BTreeMap, BlockHeader,
+    ChainMmr, Digest, Felt, Hasher, MerkleStore, PreparedTransactionError, StarkField,
+    StorageSlotType, ToAdviceInputs, TransactionResultError, TransactionWitnessError,
+    TryFromVmResult, Vec, Word, WORD_SIZE, ZERO,
 };
 use miden_core::{Program, StackInputs, StackOutputs};
 
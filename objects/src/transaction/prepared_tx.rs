@@ -53,6 +53,11 @@ impl PreparedTransaction {
         &self.account
     }
 
+    /// Returns the account seed if the account the transaction is being executed against is new.
+    pub fn account_seed(&self) -> Option<Word> {
+        self.account_seed
+    }
+
     /// Returns the block header.
     pub fn block_header(&self) -> &BlockHeader {
         &self.block_header
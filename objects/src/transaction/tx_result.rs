@@ -1,10 +1,15 @@
 use super::{
-    Account, AccountDelta, AccountError, AccountId, AccountStorage, AccountStub, BTreeMap,
-    ConsumedNotes, CreatedNotes, Digest, Felt, MerkleStore, Program, StackOutputs,
-    TransactionResultError, TransactionWitness, TryFromVmResult, Vec, Word, WORD_SIZE,
+    Account, AccountCode, AccountDelta, AccountError, AccountId, AccountStorage,
+    AccountStorageMode, AccountStub, BTreeMap, ConsumedNotes, CreatedNotes, Digest, Felt,
+    MerkleStore, Program, StackOutputs, StorageSlotType, TransactionResultError,
+    TransactionWitness, TryFromVmResult, Vec, Word, WORD_SIZE,
 };
-use crate::accounts::AccountStorageDelta;
-use crypto::merkle::{merkle_tree_delta, MerkleStoreDelta, MerkleTreeDelta, NodeIndex};
+use crate::{
+    accounts::{AccountStorageDelta, AccountVaultDelta},
+    assets::{Asset, FungibleAsset},
+};
+use core::cmp::Ordering;
+use crypto::merkle::{merkle_tree_delta, EmptySubtreeRoots, MerkleStoreDelta, NodeIndex};
 use miden_core::utils::group_slice_elements;
 use miden_lib::memory::{
     ACCT_CODE_ROOT_OFFSET, ACCT_DATA_MEM_SIZE, ACCT_ID_AND_NONCE_OFFSET, ACCT_ID_IDX,
@@ -19,18 +24,31 @@ use miden_processor::{AdviceInputs, RecAdviceProvider};
 /// - initial_account_hash: the initial account hash.
 /// - final_account_hash: the final account hash.
 /// - account_delta: a delta between the initial and final accounts.
+/// - new_account: the initial state of the account and the seed used to derive its ID if the
+///   account was created by the transaction.
+/// - updated_account_code: the code the account was updated to if the transaction updated the
+///   account code.
 /// - consumed_notes: the notes consumed by the transaction.
 /// - created_notes: the notes created by the transaction.
 /// - block_hash: the hash of the block against which the transaction was executed.
 /// - program: the program that was executed.
 /// - tx_script_root: the script root of the transaction.
 /// - advice_witness: an advice witness that contains the minimum required data to execute a tx.
+///
+/// The account delta and the new account data are only retained for accounts whose state is stored
+/// on-chain (public and encrypted accounts). For private accounts only the account hashes and the
+/// updated account code are retained, as the code is required to execute subsequent transactions
+/// against the account. Thus, [TransactionResult::account_delta()] and
+/// [TransactionResult::new_account()] always return None for private accounts, and the local state
+/// of a private account can not be updated from the transaction result alone.
 #[derive(Debug, Clone)]
 pub struct TransactionResult {
     account_id: AccountId,
     initial_account_hash: Digest,
     final_account_hash: Digest,
-    account_delta: AccountDelta,
+    account_delta: Option<AccountDelta>,
+    new_account: Option<(Account, Word)>,
+    updated_account_code: Option<AccountCode>,
     consumed_notes: ConsumedNotes,
     created_notes: CreatedNotes,
     block_hash: Digest,
@@ -43,9 +61,22 @@ impl TransactionResult {
     // CONSTRUCTORS
     // --------------------------------------------------------------------------------------------
     /// Creates a new [TransactionResult] from the provided data, advice provider and stack outputs.
+    ///
+    /// If the transaction is executed against a new account, `account_seed` must contain the seed
+    /// used to derive the account ID. If the account code was updated during the transaction,
+    /// `account_code_updates` must contain the code the account was updated to keyed by its root.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The final account data or the created notes data could not be extracted.
+    /// - The account delta could not be extracted.
+    /// - The account code was updated but the updated code was not provided.
+    /// - The account is new and the account seed was not provided.
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         initial_account: Account,
+        account_seed: Option<Word>,
+        account_code_updates: &BTreeMap<Digest, AccountCode>,
         consumed_notes: ConsumedNotes,
         block_hash: Digest,
         program: Program,
@@ -61,10 +92,9 @@ impl TransactionResult {
             FinalAccountStub::try_from_vm_result(&stack_outputs, &stack, &map, &store)?;
         let created_notes = CreatedNotes::try_from_vm_result(&stack_outputs, &stack, &map, &store)?;
 
-        // TODO: Fix delta extraction for new account creation
         // extract the account storage delta
         let storage_delta =
-            extract_account_storage_delta(&store, &initial_account, &final_account_stub)?;
+            extract_account_storage_delta(&store, &map, &initial_account, &final_account_stub)?;
 
         // extract the nonce delta
         let nonce_delta = if initial_account.nonce() != final_account_stub.0.nonce() {
@@ -73,22 +103,58 @@ impl TransactionResult {
             None
         };
 
-        // TODO: implement vault delta extraction
-        let vault_delta = MerkleTreeDelta::new(0);
+        // extract the account vault delta
+        let vault_delta =
+            extract_account_vault_delta(&store, &map, &initial_account, &final_account_stub)?;
+
+        // extract the code the account was updated to; the code of a new account is included in
+        // the code delta even if it was not updated
+        let updated_account_code = extract_account_code_update(
+            &initial_account,
+            account_code_updates,
+            &final_account_stub,
+        )?;
+        let code_delta = match &updated_account_code {
+            Some(code) => Some(code.module().clone()),
+            None => initial_account.is_new().then(|| initial_account.code().module().clone()),
+        };
 
         // construct the account delta
         let account_delta = AccountDelta {
-            code: None,
+            code: code_delta,
             nonce: nonce_delta,
             storage: storage_delta,
             vault: vault_delta,
         };
 
+        // if the account is new, retain its initial state and seed
+        let account_id = initial_account.id();
+        let initial_account_hash = initial_account.hash();
+        let new_account = if initial_account.is_new() {
+            let seed = account_seed.ok_or(TransactionResultError::NewAccountSeedNotProvided)?;
+            Some((initial_account, seed))
+        } else {
+            None
+        };
+
+        // the state of private accounts is not stored on-chain, so only the account hashes are
+        // retained for them
+        let storage_mode =
+            account_id.storage_mode().map_err(TransactionResultError::AccountIdInvalid)?;
+        let (account_delta, new_account) = match storage_mode {
+            AccountStorageMode::Public | AccountStorageMode::Encrypted => {
+                (Some(account_delta), new_account)
+            }
+            AccountStorageMode::Private => (None, None),
+        };
+
         Ok(Self {
-            account_id: initial_account.id(),
-            initial_account_hash: initial_account.hash(),
+            account_id,
+            initial_account_hash,
             final_account_hash: final_account_stub.0.hash(),
             account_delta,
+            new_account,
+            updated_account_code,
             consumed_notes,
             created_notes,
             block_hash,
@@ -116,9 +182,21 @@ impl TransactionResult {
         self.final_account_hash
     }
 
-    /// Returns a reference to the account delta.
-    pub fn account_delta(&self) -> &AccountDelta {
-        &self.account_delta
+    /// Returns a reference to the account delta, or None if the account is private.
+    pub fn account_delta(&self) -> Option<&AccountDelta> {
+        self.account_delta.as_ref()
+    }
+
+    /// Returns a reference to the initial state of the account and the seed used to derive its ID
+    /// if the account was created by this transaction and is not private.
+    pub fn new_account(&self) -> Option<(&Account, Word)> {
+        self.new_account.as_ref().map(|(account, seed)| (account, *seed))
+    }
+
+    /// Returns a reference to the code the account was updated to if this transaction updated the
+    /// account code.
+    pub fn updated_account_code(&self) -> Option<&AccountCode> {
+        self.updated_account_code.as_ref()
     }
 
     /// Returns a reference to the consumed notes.
@@ -225,9 +303,13 @@ fn parse_stub(elements: &[Word]) -> Result<AccountStub, AccountError> {
 // ACCOUNT STORAGE DELTA
 // ================================================================================================
 /// Extracts account storage delta between the `initial_account` and `final_account_stub` from the
-/// provided `MerkleStore`
+/// provided `MerkleStore` and advice map.
+///
+/// The items of the updated storage maps are extracted from the tiered sparse Merkle trees backing
+/// the maps, whose leaves are read from the advice map.
 fn extract_account_storage_delta(
     store: &MerkleStore,
+    advice_map: &BTreeMap<[u8; 32], Vec<Felt>>,
     initial_account: &Account,
     final_account_stub: &FinalAccountStub,
 ) -> Result<AccountStorageDelta, TransactionResultError> {
@@ -242,19 +324,37 @@ fn extract_account_storage_delta(
 
     // extract child deltas
     let mut store_delta = vec![];
+    let mut maps_delta = BTreeMap::new();
     for (slot, new_value) in slots_delta.updated_slots() {
-        // if a slot was updated, check if it was originally a Merkle root of a Merkle tree
-        let leaf = store
-            .get_node(
-                initial_account.storage().root(),
-                NodeIndex::new_unchecked(AccountStorage::STORAGE_TREE_DEPTH, *slot),
-            )
-            .expect("storage slut must exist");
-        // if a slot was a Merkle root then extract the delta.  We assume the tree is a SMT of depth 64.
-        if store.get_node(leaf, NodeIndex::new_unchecked(0, 0)).is_ok() {
-            let child_delta = merkle_tree_delta(leaf, (*new_value).into(), 64, store)
+        // storage maps are backed by tiered sparse Merkle trees, so the updated map items are
+        // extracted from the leaves which differ between the initial and the final map trees
+        if let Some(map) = initial_account.storage().maps().get(&(*slot as u8)) {
+            let (initial_leaves, final_leaves) =
+                tiered_smt_leaves_diff(store, advice_map, map.root(), (*new_value).into())?;
+
+            let mut items = final_leaves
+                .iter()
+                .filter(|(key, leaf)| initial_leaves.get(*key) != Some(*leaf))
+                .map(|(_, leaf)| *leaf)
+                .collect::<Vec<_>>();
+            items.extend(
+                initial_leaves
+                    .iter()
+                    .filter(|(key, _)| !final_leaves.contains_key(*key))
+                    .map(|(_, (key, _))| (*key, Word::default())),
+            );
+            maps_delta.insert(*slot as u8, items);
+            continue;
+        }
+
+        // array slots hold the roots of sparse Merkle trees of the depth specified by the slot
+        // type, so the delta of the array is extracted from the initial and the final trees
+        let storage = initial_account.storage();
+        if let StorageSlotType::Array { depth } = storage.get_slot_type(*slot as u8) {
+            let initial_root = storage.get_item(*slot as u8);
+            let child_delta = merkle_tree_delta(initial_root, (*new_value).into(), depth, store)
                 .map_err(TransactionResultError::ExtractAccountStorageStoreDeltaFailed)?;
-            store_delta.push((leaf, child_delta));
+            store_delta.push((initial_root, child_delta));
         }
     }
 
@@ -262,7 +362,211 @@ fn extract_account_storage_delta(
     let storage_delta = AccountStorageDelta {
         slots_delta,
         store_delta: MerkleStoreDelta(store_delta),
+        maps_delta,
     };
 
     Ok(storage_delta)
 }
+
+// ACCOUNT CODE UPDATE
+// ================================================================================================
+/// Extracts the code the account was updated to between the `initial_account` and
+/// `final_account_stub`.
+///
+/// Returns None if the account code root has not changed over the course of the transaction.
+/// Otherwise, the code must be present in `account_code_updates` under the final account code
+/// root.
+fn extract_account_code_update(
+    initial_account: &Account,
+    account_code_updates: &BTreeMap<Digest, AccountCode>,
+    final_account_stub: &FinalAccountStub,
+) -> Result<Option<AccountCode>, TransactionResultError> {
+    let final_code_root = final_account_stub.0.code_root();
+
+    if initial_account.code().root() == final_code_root {
+        return Ok(None);
+    }
+
+    account_code_updates
+        .get(&final_code_root)
+        .cloned()
+        .map(Some)
+        .ok_or(TransactionResultError::UpdatedAccountCodeNotProvided(final_code_root))
+}
+
+// ACCOUNT VAULT DELTA
+// ================================================================================================
+/// Extracts account vault delta between the `initial_account` and `final_account_stub` from the
+/// provided `MerkleStore` and advice map.
+///
+/// The assets held by the leaves which differ between the initial and the final vault trees are
+/// compared to compute the assets which were added to and removed from the vault.
+fn extract_account_vault_delta(
+    store: &MerkleStore,
+    advice_map: &BTreeMap<[u8; 32], Vec<Felt>>,
+    initial_account: &Account,
+    final_account_stub: &FinalAccountStub,
+) -> Result<AccountVaultDelta, TransactionResultError> {
+    let initial_root = initial_account.vault().commitment();
+    let final_root = final_account_stub.0.vault_root();
+
+    // if the vault root has not changed there is nothing to extract
+    if initial_root == final_root {
+        return Ok(AccountVaultDelta::default());
+    }
+
+    // collect the leaves which differ between the initial and the final vault trees
+    let (initial_leaves, final_leaves) =
+        tiered_smt_leaves_diff(store, advice_map, initial_root, final_root)?;
+
+    // compare the collected assets to compute the vault delta
+    let mut vault_delta = AccountVaultDelta::default();
+    for (key, (_, initial_value)) in initial_leaves.iter() {
+        match final_leaves.get(key).map(|(_, value)| value) {
+            Some(final_value) if final_value == initial_value => (),
+            Some(final_value) => {
+                // only fungible assets can change value while retaining their vault key
+                let initial_asset = FungibleAsset::new_unchecked(*initial_value);
+                let final_asset = FungibleAsset::new_unchecked(*final_value);
+                let faucet_id = initial_asset.faucet_id();
+                match final_asset.amount().cmp(&initial_asset.amount()) {
+                    Ordering::Greater => {
+                        let amount = final_asset.amount() - initial_asset.amount();
+                        vault_delta.added_assets.push(
+                            FungibleAsset::new(faucet_id, amount)
+                                .expect("amount difference is a valid amount")
+                                .into(),
+                        );
+                    }
+                    Ordering::Less => {
+                        let amount = initial_asset.amount() - final_asset.amount();
+                        vault_delta.removed_assets.push(
+                            FungibleAsset::new(faucet_id, amount)
+                                .expect("amount difference is a valid amount")
+                                .into(),
+                        );
+                    }
+                    Ordering::Equal => (),
+                }
+            }
+            None => vault_delta.removed_assets.push(Asset::new_unchecked(*initial_value)),
+        }
+    }
+
+    for (key, (_, final_value)) in final_leaves.iter() {
+        if !initial_leaves.contains_key(key) {
+            vault_delta.added_assets.push(Asset::new_unchecked(*final_value));
+        }
+    }
+
+    Ok(vault_delta)
+}
+
+// TIERED SPARSE MERKLE TREE LEAVES
+// ================================================================================================
+/// The depth of the tiered sparse Merkle trees backing the account vault and storage maps.
+const TIERED_SMT_DEPTH: u8 = 64;
+
+/// The depths at which leaves of a tiered sparse Merkle tree can be located.
+const TIERED_SMT_TIER_DEPTHS: [u8; 4] = [16, 32, 48, 64];
+
+/// The (key, value) pairs of tiered sparse Merkle tree leaves, keyed by the bytes of the key.
+type TieredSmtLeaves = BTreeMap<[u8; 32], (Word, Word)>;
+
+/// Returns the (key, value) pairs held by the leaves which differ between the tiered sparse Merkle
+/// trees with the specified roots, as a tuple of the pairs found in the initial and in the final
+/// tree.
+///
+/// Both trees are traversed starting from their roots, skipping subtrees which are empty or
+/// identical in both trees. The leaves encountered in the remaining subtrees are read from the
+/// advice map.
+fn tiered_smt_leaves_diff(
+    store: &MerkleStore,
+    advice_map: &BTreeMap<[u8; 32], Vec<Felt>>,
+    initial_root: Digest,
+    final_root: Digest,
+) -> Result<(TieredSmtLeaves, TieredSmtLeaves), TransactionResultError> {
+    let mut initial_leaves = BTreeMap::new();
+    let mut final_leaves = BTreeMap::new();
+    if initial_root == final_root {
+        return Ok((initial_leaves, final_leaves));
+    }
+
+    collect_tiered_smt_leaves(
+        store,
+        advice_map,
+        initial_root,
+        final_root,
+        NodeIndex::root(),
+        initial_root,
+        &mut initial_leaves,
+    )?;
+    collect_tiered_smt_leaves(
+        store,
+        advice_map,
+        final_root,
+        initial_root,
+        NodeIndex::root(),
+        final_root,
+        &mut final_leaves,
+    )?;
+
+    Ok((initial_leaves, final_leaves))
+}
+
+/// Collects (key, value) pairs stored in the subtree of the tiered sparse Merkle tree with the
+/// specified `root` located at `index`, skipping any subtrees which are empty or which are
+/// identical to the subtrees at the same index in the tree with `other_root`.
+///
+/// The contents of leaf nodes are expected to be present in the advice map as sequences of
+/// (key, value) word pairs.
+fn collect_tiered_smt_leaves(
+    store: &MerkleStore,
+    advice_map: &BTreeMap<[u8; 32], Vec<Felt>>,
+    root: Digest,
+    other_root: Digest,
+    index: NodeIndex,
+    node: Digest,
+    leaves: &mut TieredSmtLeaves,
+) -> Result<(), TransactionResultError> {
+    // skip subtrees which are empty or identical in both trees
+    if node == *EmptySubtreeRoots::entry(TIERED_SMT_DEPTH, index.depth())
+        || store.get_node(other_root, index).map_or(false, |other| other == node)
+    {
+        return Ok(());
+    }
+
+    // nodes located at tier depths may be leaves, in which case their contents are stored in
+    // the advice map
+    if TIERED_SMT_TIER_DEPTHS.contains(&index.depth()) {
+        if let Some(leaf_data) = advice_map.get(&node.as_bytes()) {
+            if leaf_data.is_empty() || leaf_data.len() % (2 * WORD_SIZE) != 0 {
+                return Err(TransactionResultError::TieredSmtLeafDataInvalid(node));
+            }
+
+            for entry in leaf_data.chunks_exact(2 * WORD_SIZE) {
+                let key: Word = entry[..WORD_SIZE].try_into().expect("word size is correct");
+                let value: Word = entry[WORD_SIZE..].try_into().expect("word size is correct");
+                leaves.insert(Digest::from(key).as_bytes(), (key, value));
+            }
+
+            return Ok(());
+        }
+    }
+
+    // nodes at the bottom tier can only be leaves
+    if index.depth() == TIERED_SMT_DEPTH {
+        return Err(TransactionResultError::TieredSmtLeafDataNotFound(node));
+    }
+
+    // otherwise this is an internal node and we descend into its children
+    for value in [index.value() * 2, index.value() * 2 + 1] {
+        let child_index = NodeIndex::new_unchecked(index.depth() + 1, value);
+        let child = store
+            .get_node(root, child_index)
+            .map_err(TransactionResultError::ExtractTieredSmtLeavesFailed)?;
+        collect_tiered_smt_leaves(store, advice_map, root, other_root, child_index, child, leaves)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use super::{
+    Account, AccountBuilder, AccountError, AccountStorageMode, AccountType, Assembler, ModuleAst,
+    StorageItem, Word,
+};
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Index of the account storage slot at which the public key of the wallet owner is stored.
+///
+/// This must match the `PUBLIC_KEY_SLOT` constant of the `miden::wallets::basic` module, as the
+/// slot is read by its `auth_tx` procedure.
+pub const BASIC_WALLET_PUBLIC_KEY_SLOT: u8 = 0;
+
+/// Source code of the account module which exposes the `miden::wallets::basic` procedures.
+pub(crate) const BASIC_WALLET_CODE: &str = "
+    use.miden::wallets::basic->basic_wallet
+
+    export.receive_asset
+        exec.basic_wallet::receive_asset
+    end
+
+    export.send_asset
+        exec.basic_wallet::send_asset
+    end
+
+    export.auth_tx
+        exec.basic_wallet::auth_tx
+    end
+";
+
+// BASIC WALLET
+// ================================================================================================
+
+/// Returns a new basic wallet account owned by the holder of the secret key associated with the
+/// specified public key, together with the seed from which the account ID was derived.
+///
+/// The account code exposes `receive_asset`, `send_asset` and `auth_tx` procedures from the
+/// `miden::wallets::basic` module. The public key is placed into the storage slot at index
+/// [BASIC_WALLET_PUBLIC_KEY_SLOT]. As the wallet code cannot be updated, the account is created as
+/// a [AccountType::RegularAccountImmutableCode] account.
+///
+/// The provided assembler must be instantiated with the Miden library, the standard library and
+/// the transaction kernel.
+///
+/// # Errors
+/// Returns an error if the account code could not be compiled or a valid account seed could not
+/// be found.
+pub fn create_basic_wallet(
+    init_seed: [u8; 32],
+    pub_key: Word,
+    storage_mode: AccountStorageMode,
+    assembler: &Assembler,
+) -> Result<(Account, Word), AccountError> {
+    basic_wallet_builder(init_seed, pub_key, storage_mode)?.build(assembler)
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns the builder of a basic wallet owned by the holder of the secret key associated with the
+/// specified public key.
+fn basic_wallet_builder(
+    init_seed: [u8; 32],
+    pub_key: Word,
+    storage_mode: AccountStorageMode,
+) -> Result<AccountBuilder, AccountError> {
+    Ok(AccountBuilder::new(init_seed, ModuleAst::parse(BASIC_WALLET_CODE)?)
+        .account_type(AccountType::RegularAccountImmutableCode)
+        .storage_mode(storage_mode)
+        .storage_item(StorageItem::new_value(BASIC_WALLET_PUBLIC_KEY_SLOT, pub_key)))
+}
@@ -0,0 +1,46 @@
+use super::{
+    basic_wallet_builder, create_basic_wallet, AccountStorageMode, AccountType,
+    BASIC_WALLET_PUBLIC_KEY_SLOT,
+};
+use crate::{
+    mock::{assembler, mock_basic_wallet, ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN},
+    validate_account_seed, AccountId, AccountVault,
+};
+use crypto::{Felt, Word, ZERO};
+
+#[test]
+fn test_basic_wallet_builder() {
+    let mut assembler = assembler();
+    let pub_key: Word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+
+    // the wallet is built with a fixed ID, so that no account seed needs to be ground
+    let wallet = basic_wallet_builder([7; 32], pub_key, AccountStorageMode::Public)
+        .unwrap()
+        .build_with_id(account_id, &assembler)
+        .unwrap();
+
+    assert_eq!(wallet.code().procedures().len(), 3);
+    assert_eq!(Word::from(wallet.storage().get_item(BASIC_WALLET_PUBLIC_KEY_SLOT)), pub_key);
+    assert_eq!(wallet.vault().assets().count(), 0);
+    assert_eq!(wallet.nonce(), ZERO);
+
+    // the wallet matches the mock basic wallet used to test transactions against wallets
+    let mock_wallet = mock_basic_wallet(pub_key, AccountVault::default(), ZERO, &mut assembler);
+    assert_eq!(wallet.hash(), mock_wallet.hash());
+}
+
+#[test]
+#[ignore = "grinding a regular account seed requires computing ~2^23 hashes"]
+fn test_create_basic_wallet() {
+    let pub_key: Word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+
+    let (wallet, seed) =
+        create_basic_wallet([7; 32], pub_key, AccountStorageMode::Private, &assembler()).unwrap();
+
+    assert_eq!(wallet.id().account_type(), AccountType::RegularAccountImmutableCode);
+    assert!(validate_account_seed(&wallet, seed).is_ok());
+    assert_eq!(wallet.code().procedures().len(), 3);
+    assert_eq!(Word::from(wallet.storage().get_item(BASIC_WALLET_PUBLIC_KEY_SLOT)), pub_key);
+}